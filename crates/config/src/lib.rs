@@ -1,17 +1,112 @@
+use std::collections::HashMap;
+use std::fs;
 use std::path::PathBuf;
 
 use config_better::Config as CBConfig;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use toml_edit::DocumentMut;
+
+const SETTINGS_FILE: &str = "config.toml";
+const STATE_FILE: &str = "state.toml";
+
+/// Ready checks expire after 10 seconds, leave a little time for the accept request itself.
+pub const MAX_AUTO_ACCEPT_DELAY_SECS: u64 = 8;
 
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("Could not create app directories.")]
     CouldNotMakeDirs,
+    #[error("Could not read settings file.")]
+    CouldNotReadSettings(#[source] std::io::Error),
+    #[error("Could not parse settings file.")]
+    CouldNotParseSettings(#[from] toml::de::Error),
+    #[error("Could not write settings file.")]
+    CouldNotWriteSettings(#[source] std::io::Error),
+    #[error("Could not edit settings file.")]
+    CouldNotEditSettings(#[from] toml_edit::TomlError),
+    #[error("Could not read state file.")]
+    CouldNotReadState(#[source] std::io::Error),
+    #[error("Could not serialize state.")]
+    CouldNotSerializeState(#[from] toml::ser::Error),
+    #[error("Could not write state file.")]
+    CouldNotWriteState(#[source] std::io::Error),
+}
+
+/// User preferences, persisted as TOML in the config directory.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Champion names the user plays, keyed by role name (`top`, `jungle`, `mid`, `adc`,
+    /// `support`).
+    pub champion_pool: HashMap<String, Vec<String>>,
+    pub auto_accept: AutoAcceptSettings,
+    pub theme: ThemeName,
+    /// Overrides for the default shortcuts, keyed by action name, e.g.
+    /// `mode_select = "ctrl+m"`.
+    pub keybindings: HashMap<String, String>,
+}
+
+/// What uggo remembers between runs, kept apart from the hand-edited settings file since it's
+/// rewritten on every change.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppState {
+    /// Data Dragon ids of starred champions, pinned to the top of the champion list when not
+    /// searching.
+    pub favorites: Vec<String>,
+    /// Data Dragon ids of the last viewed champion and the one before it, for the quick switch.
+    pub recent_champions: Vec<String>,
+}
+
+/// Built-in color palettes, selected with `theme = "..."` in the settings file.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    /// Avoids relying on red/green contrast.
+    Deuteranopia,
+}
+
+/// Accepting ready checks automatically, see `[auto_accept]` in the settings file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoAcceptSettings {
+    pub enabled: bool,
+    /// Seconds to wait before accepting, leaving time to decline manually.
+    pub delay_secs: u64,
+}
+
+impl Default for AutoAcceptSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay_secs: 3,
+        }
+    }
+}
+
+impl AutoAcceptSettings {
+    /// Shortens a delay that would outlast the ready check, which could then never be accepted.
+    fn clamp_delay(&mut self) {
+        if self.delay_secs > MAX_AUTO_ACCEPT_DELAY_SECS {
+            log::warn!(
+                "Auto-accept delay of {}s is longer than a ready check lasts, using {MAX_AUTO_ACCEPT_DELAY_SECS}s",
+                self.delay_secs
+            );
+            self.delay_secs = MAX_AUTO_ACCEPT_DELAY_SECS;
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct Config {
     inner: CBConfig,
+    settings: Settings,
+    state: AppState,
 }
 
 impl Config {
@@ -22,11 +117,215 @@ impl Config {
             .create_all()
             .map_err(|_| ConfigError::CouldNotMakeDirs)?;
 
-        Ok(Self { inner: config })
+        let settings_path = config.config.path.join(SETTINGS_FILE);
+        let mut settings: Settings = if settings_path.exists() {
+            toml::from_str(
+                &fs::read_to_string(&settings_path).map_err(ConfigError::CouldNotReadSettings)?,
+            )?
+        } else {
+            Settings::default()
+        };
+        settings.auto_accept.clamp_delay();
+
+        let state_path = config.data.path.join(STATE_FILE);
+        // The app rewrites the state file on every change, so a broken one isn't worth failing
+        // startup over.
+        let state = if state_path.exists() {
+            toml::from_str(
+                &fs::read_to_string(&state_path).map_err(ConfigError::CouldNotReadState)?,
+            )
+            .unwrap_or_else(|e| {
+                log::warn!("Could not parse state file, starting with an empty one: {e}");
+                AppState::default()
+            })
+        } else {
+            AppState::default()
+        };
+
+        Ok(Self {
+            inner: config,
+            settings,
+            state,
+        })
     }
 
     #[must_use]
     pub fn cache(&self) -> &PathBuf {
         &self.inner.cache.path
     }
+
+    #[must_use]
+    pub fn settings_path(&self) -> PathBuf {
+        self.inner.config.path.join(SETTINGS_FILE)
+    }
+
+    #[must_use]
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Turns ready check auto-accept on or off, leaving the rest of the settings file (including
+    /// comments) as the user wrote it.
+    pub fn set_auto_accept(&mut self, enabled: bool) -> Result<(), ConfigError> {
+        self.settings.auto_accept.enabled = enabled;
+        self.edit_settings_file(|doc| doc["auto_accept"]["enabled"] = toml_edit::value(enabled))
+    }
+
+    fn edit_settings_file(&self, edit: impl FnOnce(&mut DocumentMut)) -> Result<(), ConfigError> {
+        let path = self.settings_path();
+        let contents = if path.exists() {
+            fs::read_to_string(&path).map_err(ConfigError::CouldNotReadSettings)?
+        } else {
+            String::new()
+        };
+        fs::write(path, edit_toml(&contents, edit)?).map_err(ConfigError::CouldNotWriteSettings)
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut AppState {
+        &mut self.state
+    }
+
+    pub fn save_state(&self) -> Result<(), ConfigError> {
+        fs::write(
+            self.inner.data.path.join(STATE_FILE),
+            toml::to_string_pretty(&self.state)?,
+        )
+        .map_err(ConfigError::CouldNotWriteState)
+    }
+}
+
+fn edit_toml(contents: &str, edit: impl FnOnce(&mut DocumentMut)) -> Result<String, ConfigError> {
+    let mut doc = contents.parse::<DocumentMut>()?;
+    edit(&mut doc);
+    Ok(doc.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_settings_use_defaults() {
+        let settings: Settings = toml::from_str("").unwrap();
+        assert_eq!(settings, Settings::default());
+    }
+
+    #[test]
+    fn champion_pool_parses() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [champion_pool]
+            top = ["Darius", "Garen"]
+            mid = ["Ahri"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings.champion_pool.get("top"),
+            Some(&vec!["Darius".to_string(), "Garen".to_string()])
+        );
+        assert_eq!(settings.champion_pool.get("mid").map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn auto_accept_keeps_default_delay() {
+        let settings: Settings = toml::from_str(
+            r"
+            [auto_accept]
+            enabled = true
+            ",
+        )
+        .unwrap();
+
+        assert!(settings.auto_accept.enabled);
+        assert_eq!(settings.auto_accept.delay_secs, 3);
+    }
+
+    #[test]
+    fn auto_accept_delay_fits_ready_check() {
+        let mut settings = AutoAcceptSettings {
+            enabled: true,
+            delay_secs: 30,
+        };
+        settings.clamp_delay();
+        assert_eq!(settings.delay_secs, MAX_AUTO_ACCEPT_DELAY_SECS);
+
+        settings.delay_secs = 5;
+        settings.clamp_delay();
+        assert_eq!(settings.delay_secs, 5);
+    }
+
+    #[test]
+    fn theme_parses_kebab_case() {
+        let settings: Settings = toml::from_str(r#"theme = "high-contrast""#).unwrap();
+        assert_eq!(settings.theme, ThemeName::HighContrast);
+        assert_eq!(Settings::default().theme, ThemeName::Dark);
+    }
+
+    #[test]
+    fn keybindings_parse() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [keybindings]
+            search = "ctrl+f"
+            scroll_down = "pgdn"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings.keybindings.get("search").map(String::as_str),
+            Some("ctrl+f")
+        );
+        assert_eq!(settings.keybindings.len(), 2);
+    }
+
+    #[test]
+    fn state_round_trips() {
+        let state = AppState {
+            favorites: vec!["Ahri".to_string(), "MonkeyKing".to_string()],
+            recent_champions: vec!["Ahri".to_string()],
+        };
+
+        let parsed: AppState = toml::from_str(&toml::to_string_pretty(&state).unwrap()).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn editing_keeps_comments() {
+        let contents = r#"# My settings
+theme = "light" # easier to read
+
+[auto_accept]
+# Give me time to decline.
+enabled = false
+delay_secs = 5
+"#;
+
+        let edited = edit_toml(contents, |doc| {
+            doc["auto_accept"]["enabled"] = toml_edit::value(true);
+        })
+        .unwrap();
+        assert_eq!(
+            edited,
+            contents.replace("enabled = false", "enabled = true")
+        );
+    }
+
+    #[test]
+    fn editing_adds_missing_tables() {
+        let edited = edit_toml("", |doc| {
+            doc["auto_accept"]["enabled"] = toml_edit::value(true);
+        })
+        .unwrap();
+
+        let settings: Settings = toml::from_str(&edited).unwrap();
+        assert!(settings.auto_accept.enabled);
+    }
 }
@@ -0,0 +1,115 @@
+use ugg_types::client_gameflow::GameflowPhase;
+
+/// A change from one gameflow phase to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameflowEvent {
+    pub from: GameflowPhase,
+    pub to: GameflowPhase,
+}
+
+impl GameflowEvent {
+    /// Whether this transition moved into `phase`.
+    #[must_use]
+    pub fn entered(&self, phase: GameflowPhase) -> bool {
+        self.from != phase && self.to == phase
+    }
+
+    /// Whether this transition moved out of `phase`.
+    #[must_use]
+    pub fn left(&self, phase: GameflowPhase) -> bool {
+        self.from == phase && self.to != phase
+    }
+
+    /// Whether a game that was in progress (or finishing up) is now over.
+    #[must_use]
+    pub const fn game_ended(&self) -> bool {
+        (self.from.is_in_game() || self.from.is_post_game())
+            && !self.to.is_in_game()
+            && !self.to.is_post_game()
+    }
+}
+
+/// Keeps track of the last observed gameflow phase, turning a stream of polled phases into
+/// transition events.
+#[derive(Debug, Default, Clone)]
+pub struct GameflowTracker {
+    phase: GameflowPhase,
+}
+
+impl GameflowTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn phase(&self) -> GameflowPhase {
+        self.phase
+    }
+
+    /// Records a newly observed phase, returning the transition if it differs from the last one.
+    pub fn update(&mut self, phase: GameflowPhase) -> Option<GameflowEvent> {
+        if phase == self.phase {
+            return None;
+        }
+
+        let event = GameflowEvent {
+            from: self.phase,
+            to: phase,
+        };
+        self.phase = phase;
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_phase_is_not_a_transition() {
+        let mut tracker = GameflowTracker::new();
+        assert_eq!(tracker.update(GameflowPhase::None), None);
+
+        assert!(tracker.update(GameflowPhase::Lobby).is_some());
+        assert_eq!(tracker.update(GameflowPhase::Lobby), None);
+        assert_eq!(tracker.phase(), GameflowPhase::Lobby);
+    }
+
+    #[test]
+    fn transition_reports_both_phases() {
+        let mut tracker = GameflowTracker::new();
+        tracker.update(GameflowPhase::Matchmaking);
+
+        let event = tracker.update(GameflowPhase::ReadyCheck).unwrap();
+        assert_eq!(event.from, GameflowPhase::Matchmaking);
+        assert_eq!(event.to, GameflowPhase::ReadyCheck);
+        assert!(event.entered(GameflowPhase::ReadyCheck));
+        assert!(event.left(GameflowPhase::Matchmaking));
+        assert!(!event.entered(GameflowPhase::ChampSelect));
+    }
+
+    #[test]
+    fn game_end_is_detected_once_leaving_post_game() {
+        let mut tracker = GameflowTracker::new();
+        tracker.update(GameflowPhase::InProgress);
+
+        let to_stats = tracker.update(GameflowPhase::WaitingForStats).unwrap();
+        assert!(!to_stats.game_ended());
+
+        let to_eog = tracker.update(GameflowPhase::EndOfGame).unwrap();
+        assert!(!to_eog.game_ended());
+
+        let to_lobby = tracker.update(GameflowPhase::Lobby).unwrap();
+        assert!(to_lobby.game_ended());
+    }
+
+    #[test]
+    fn dodged_champ_select_is_not_a_game_end() {
+        let mut tracker = GameflowTracker::new();
+        tracker.update(GameflowPhase::ChampSelect);
+
+        let event = tracker.update(GameflowPhase::Lobby).unwrap();
+        assert!(!event.game_ended());
+    }
+}
@@ -1,16 +1,29 @@
-use std::sync::Arc;
 use native_tls::TlsConnector;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::time::Instant;
 use thiserror::Error;
 use ureq::{Agent, AgentBuilder};
 
 use ugg_types::client_champ_select::ChampSelectSession;
+use ugg_types::client_champions::ClientChampions;
+use ugg_types::client_chat::{ChatConversation, NewChatMessage};
+use ugg_types::client_gameflow::GameflowPhase;
+use ugg_types::client_mastery::ChampionMasteries;
+use ugg_types::client_ranked::RankedStats;
+use ugg_types::client_ready_check::ReadyCheck;
 use ugg_types::client_runepage::{NewRunePage, RunePage, RunePages};
 use ugg_types::client_summoner::ClientSummoner;
 
+mod gameflow;
 mod lcc;
+mod live;
+mod ready_check;
+pub use gameflow::{GameflowEvent, GameflowTracker};
 use lcc::{LeagueClientConnector, RiotLockFile};
+pub use live::LiveClientAPI;
+pub use ready_check::ReadyCheckAcceptor;
 
 #[derive(Error, Debug)]
 pub enum LOLClientError {
@@ -20,6 +33,10 @@ pub enum LOLClientError {
     LockfileReadError(#[from] lcc::LeagueConnectorError),
     #[error("Linux is not supported")]
     LinuxNotSupported,
+    #[error("Request to the League client failed")]
+    RequestError(#[from] Box<ureq::Error>),
+    #[error("Not in champ select")]
+    NotInChampSelect,
 }
 
 pub struct LOLClientAPI {
@@ -32,17 +49,15 @@ impl LOLClientAPI {
         if cfg!(target_os = "linux") {
             return Err(LOLClientError::LinuxNotSupported);
         }
-        
+
         // SECURITY NOTE: We must accept invalid certs because the League Client (LCU)
         // uses a self-signed certificate on localhost. This is standard practice for LCU tools.
         let tls = TlsConnector::builder()
-            .danger_accept_invalid_certs(true) 
+            .danger_accept_invalid_certs(true)
             .build()?;
 
         Ok(LOLClientAPI {
-            agent: AgentBuilder::new()
-                .tls_connector(Arc::new(tls))
-                .build(),
+            agent: AgentBuilder::new().tls_connector(Arc::new(tls)).build(),
             lockfile: LeagueClientConnector::parse_lockfile()?,
         })
     }
@@ -53,9 +68,13 @@ impl LOLClientAPI {
     }
 
     fn get_data<T: DeserializeOwned>(&self, endpoint: &str) -> Option<T> {
-        match self.agent
+        match self
+            .agent
             .get(&self.make_url(endpoint))
-            .set("Authorization", &format!("Basic {}", self.lockfile.b64_auth))
+            .set(
+                "Authorization",
+                &format!("Basic {}", self.lockfile.b64_auth),
+            )
             .call()
         {
             Ok(response) => {
@@ -70,17 +89,26 @@ impl LOLClientAPI {
     }
 
     fn delete_data(&self, endpoint: &str) {
-        let _ = self.agent
+        let _ = self
+            .agent
             .delete(&self.make_url(endpoint))
-            .set("Authorization", &format!("Basic {}", self.lockfile.b64_auth))
+            .set(
+                "Authorization",
+                &format!("Basic {}", self.lockfile.b64_auth),
+            )
             .call();
     }
 
-    fn post_data<T: Serialize>(&self, endpoint: &str, data: &T) {
-        let _ = self.agent
+    fn post_data<T: Serialize>(&self, endpoint: &str, data: &T) -> Result<(), LOLClientError> {
+        self.agent
             .post(&self.make_url(endpoint))
-            .set("Authorization", &format!("Basic {}", self.lockfile.b64_auth))
-            .send_json(data);
+            .set(
+                "Authorization",
+                &format!("Basic {}", self.lockfile.b64_auth),
+            )
+            .send_json(data)
+            .map_err(Box::new)?;
+        Ok(())
     }
 
     #[must_use]
@@ -88,22 +116,98 @@ impl LOLClientAPI {
         self.get_data::<ClientSummoner>("/lol-summoner/v1/current-summoner")
     }
 
+    #[must_use]
+    pub fn get_ranked_stats(&self) -> Option<RankedStats> {
+        self.get_data::<RankedStats>("/lol-ranked/v1/current-ranked-stats")
+    }
+
     #[must_use]
     pub fn get_current_rune_page(&self) -> Option<RunePage> {
         let pages = self.get_data::<RunePages>("/lol-perks/v1/pages")?;
         // Ưu tiên tìm trang rune của uggo trước
-        pages.iter()
+        pages
+            .iter()
             .find(|p| p.name.starts_with("uggo:") && p.is_deletable)
             .cloned()
             .or_else(|| pages.into_iter().find(|p| p.current && p.is_deletable))
     }
 
-    pub fn update_rune_page(&self, old_page_id: i64, rune_page: &NewRunePage) {
-        self.delete_data(&format!("/lol-perks/v1/pages/{}", old_page_id));
-        self.post_data("/lol-perks/v1/pages", rune_page);
+    /// Replaces the page `old_page_id` with `rune_page`.
+    pub fn update_rune_page(
+        &self,
+        old_page_id: i64,
+        rune_page: &NewRunePage,
+    ) -> Result<(), LOLClientError> {
+        self.delete_data(&format!("/lol-perks/v1/pages/{old_page_id}"));
+        self.post_data("/lol-perks/v1/pages", rune_page)
     }
 
+    #[must_use]
     pub fn get_champ_select_session(&self) -> Option<ChampSelectSession> {
         self.get_data::<ChampSelectSession>("/lol-champ-select/v1/session")
     }
+
+    #[must_use]
+    pub fn get_champ_select_conversation(&self) -> Option<ChatConversation> {
+        self.get_data::<Vec<ChatConversation>>("/lol-chat/v1/conversations")?
+            .into_iter()
+            .find(ChatConversation::is_champ_select)
+    }
+
+    /// Sends `body` to the champ select chat.
+    pub fn send_champ_select_message(&self, body: &str) -> Result<(), LOLClientError> {
+        let conversation = self
+            .get_champ_select_conversation()
+            .ok_or(LOLClientError::NotInChampSelect)?;
+        self.post_data(
+            &format!("/lol-chat/v1/conversations/{}/messages", conversation.id),
+            &NewChatMessage::new(body),
+        )
+    }
+
+    #[must_use]
+    pub fn get_owned_champions(&self) -> Option<ClientChampions> {
+        self.get_data::<ClientChampions>("/lol-champions/v1/owned-champions-minimal")
+    }
+
+    #[must_use]
+    pub fn get_champion_masteries(&self) -> Option<ChampionMasteries> {
+        self.get_data::<ChampionMasteries>("/lol-champion-mastery/v1/local-player/champion-mastery")
+    }
+
+    #[must_use]
+    pub fn get_gameflow_phase(&self) -> Option<GameflowPhase> {
+        self.get_data::<GameflowPhase>("/lol-gameflow/v1/gameflow-phase")
+    }
+
+    /// Polls the current gameflow phase and feeds it into `tracker`. An unreachable client is
+    /// treated as [`GameflowPhase::None`].
+    pub fn poll_gameflow(&self, tracker: &mut GameflowTracker) -> Option<GameflowEvent> {
+        tracker.update(self.get_gameflow_phase().unwrap_or_default())
+    }
+
+    #[must_use]
+    pub fn get_ready_check(&self) -> Option<ReadyCheck> {
+        self.get_data::<ReadyCheck>("/lol-matchmaking/v1/ready-check")
+    }
+
+    pub fn accept_ready_check(&self) -> Result<(), LOLClientError> {
+        self.post_data("/lol-matchmaking/v1/ready-check/accept", &())
+    }
+
+    /// Polls the ready check and accepts it once `acceptor`'s delay has passed. Returns whether
+    /// it was accepted; a failed request is logged and retried on the next poll.
+    pub fn poll_ready_check(&self, acceptor: &mut ReadyCheckAcceptor) -> bool {
+        let check = self.get_ready_check();
+        if !acceptor.update(check.as_ref(), Instant::now()) {
+            return false;
+        }
+        match self.accept_ready_check() {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Could not accept ready check: {e}");
+                false
+            }
+        }
+    }
 }
@@ -0,0 +1,42 @@
+use native_tls::TlsConnector;
+use std::sync::Arc;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder};
+
+use ugg_types::live_client_data::AllGameData;
+
+use crate::LOLClientError;
+
+const LIVE_CLIENT_URL: &str = "https://127.0.0.1:2999";
+
+/// Bindings for the in-game [Live Client Data API](https://developer.riotgames.com/docs/lol#game-client-api_live-client-data-api).
+/// Unlike the LCU this needs no lockfile, it's only reachable while a game is running.
+pub struct LiveClientAPI {
+    agent: Agent,
+}
+
+impl LiveClientAPI {
+    pub fn new() -> Result<LiveClientAPI, LOLClientError> {
+        // The game serves the same kind of self-signed certificate as the LCU.
+        let tls = TlsConnector::builder()
+            .danger_accept_invalid_certs(true)
+            .build()?;
+
+        Ok(LiveClientAPI {
+            agent: AgentBuilder::new()
+                .tls_connector(Arc::new(tls))
+                .timeout(Duration::from_secs(1))
+                .build(),
+        })
+    }
+
+    #[must_use]
+    pub fn get_all_game_data(&self) -> Option<AllGameData> {
+        self.agent
+            .get(&format!("{LIVE_CLIENT_URL}/liveclientdata/allgamedata"))
+            .call()
+            .ok()
+            .filter(|response| response.status() == 200)
+            .and_then(|response| response.into_json().ok())
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use ugg_types::client_ready_check::ReadyCheck;
+
+/// Accepts ready checks on our behalf, after giving us `delay` to decline ourselves.
+#[derive(Debug, Clone)]
+pub struct ReadyCheckAcceptor {
+    pub enabled: bool,
+    delay: Duration,
+    pending_since: Option<Instant>,
+}
+
+impl ReadyCheckAcceptor {
+    #[must_use]
+    pub fn new(enabled: bool, delay: Duration) -> Self {
+        Self {
+            enabled,
+            delay,
+            pending_since: None,
+        }
+    }
+
+    /// Time left before the pending ready check is accepted, if there is one.
+    #[must_use]
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.pending_since
+            .filter(|_| self.enabled)
+            .map(|since| self.delay.saturating_sub(now.duration_since(since)))
+    }
+
+    /// Records the latest ready check state, returning whether it should be accepted now.
+    pub fn update(&mut self, check: Option<&ReadyCheck>, now: Instant) -> bool {
+        if !check.is_some_and(ReadyCheck::needs_response) {
+            self.pending_since = None;
+            return false;
+        }
+
+        let since = *self.pending_since.get_or_insert(now);
+        self.enabled && now.duration_since(since) >= self.delay
+    }
+
+    /// Forgets the pending ready check, e.g. once the client has moved past it.
+    pub fn reset(&mut self) {
+        self.pending_since = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ugg_types::client_ready_check::{ReadyCheckResponse, ReadyCheckState};
+
+    fn pending() -> ReadyCheck {
+        ReadyCheck {
+            state: ReadyCheckState::InProgress,
+            player_response: ReadyCheckResponse::None,
+            timer: 0.0,
+        }
+    }
+
+    #[test]
+    fn accepts_after_delay() {
+        let start = Instant::now();
+        let mut acceptor = ReadyCheckAcceptor::new(true, Duration::from_secs(3));
+
+        assert!(!acceptor.update(Some(&pending()), start));
+        assert_eq!(
+            acceptor.remaining(start + Duration::from_secs(1)),
+            Some(Duration::from_secs(2))
+        );
+        assert!(acceptor.update(Some(&pending()), start + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn disabled_never_accepts() {
+        let start = Instant::now();
+        let mut acceptor = ReadyCheckAcceptor::new(false, Duration::ZERO);
+
+        assert!(!acceptor.update(Some(&pending()), start));
+        assert_eq!(acceptor.remaining(start), None);
+    }
+
+    #[test]
+    fn answered_check_resets_countdown() {
+        let start = Instant::now();
+        let mut acceptor = ReadyCheckAcceptor::new(true, Duration::from_secs(3));
+
+        acceptor.update(Some(&pending()), start);
+        assert!(!acceptor.update(None, start + Duration::from_secs(5)));
+        assert_eq!(acceptor.remaining(start), None);
+    }
+
+    #[test]
+    fn reset_clears_countdown() {
+        let start = Instant::now();
+        let mut acceptor = ReadyCheckAcceptor::new(true, Duration::from_secs(3));
+
+        acceptor.update(Some(&pending()), start);
+        acceptor.reset();
+        assert_eq!(acceptor.remaining(start + Duration::from_secs(1)), None);
+    }
+}
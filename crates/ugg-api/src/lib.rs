@@ -1,5 +1,7 @@
 use crate::util::sha256;
+use ddragon::cache_middleware::CacheMiddleware;
 use ddragon::models::Augment;
+use ddragon::models::champion::Champion;
 use ddragon::models::champions::ChampionShort;
 use ddragon::models::items::Item;
 use ddragon::models::runes::RuneElement;
@@ -12,10 +14,12 @@ use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
+use ugg_types::champion_search::{ChampionQuery, normalize};
 use ugg_types::mappings::{self, Rank};
 use ugg_types::matchups::{MatchupData, Matchups};
 use ugg_types::overview::{ChampOverview, Overview};
 use ugg_types::rune::RuneExtended;
+use ugg_types::stat_shards::{CDragonPerk, CDragonPerkStyles, StatShards};
 use ureq::Agent;
 
 mod util;
@@ -32,14 +36,35 @@ pub enum UggError {
     ParseError(#[from] simd_json::Error),
     #[error("Missing region or rank entry")]
     MissingRegionOrRank,
+    #[error("No data for rank {0}")]
+    MissingRank(Rank),
     #[error("Missing role entry")]
     MissingRole,
     #[error("Unknown error occurred")]
     Unknown,
 }
 
+/// Runs a lookup for the selected rank. When u.gg has no data for that rank, logs it and
+/// uses u.gg's default rank instead, so other ranks' stats are never shown silently.
+pub fn with_rank_fallback<T>(
+    rank: Option<Rank>,
+    what: &str,
+    fetch: impl Fn(Option<Rank>) -> Result<T, UggError>,
+) -> Result<T, UggError> {
+    match fetch(rank) {
+        Err(UggError::MissingRank(rank)) => {
+            log::warn!("No {rank} data for {what}, showing stats for all ranks instead");
+            fetch(None)
+        }
+        result => result,
+    }
+}
+
 pub struct DataApi {
     agent: Agent,
+    /// Caches responses on disk like the Data Dragon client, for data that only changes with
+    /// the patch.
+    cached_agent: Agent,
     ddragon: Client,
     overview_cache: RefCell<LruCache<String, ChampOverview>>,
     matchup_cache: RefCell<LruCache<String, Matchups>>,
@@ -63,6 +88,9 @@ pub struct UggApi {
     pub runes: HashMap<i64, RuneExtended<RuneElement>>,
     pub summoner_spells: HashMap<i64, String>,
     pub arena_augments: HashMap<i64, Augment>,
+    pub stat_shards: StatShards,
+    /// See [`DataApi::get_primary_roles`]. Empty if they couldn't be loaded.
+    pub primary_roles: HashMap<String, Vec<mappings::Role>>,
 }
 
 impl DataApi {
@@ -72,14 +100,17 @@ impl DataApi {
         if let Some(v) = version {
             client_builder = client_builder.version(v.as_str());
         }
+        let mut cached_agent = Agent::config_builder();
         if let Some(dir) = safe_dir.clone().to_str() {
             client_builder = client_builder.cache(dir);
+            cached_agent = cached_agent.middleware(CacheMiddleware::new(dir));
         }
 
         let cache_size = NonZeroUsize::new(50).unwrap_or(NonZeroUsize::MIN);
         Ok(Self {
             // [FIX] Sửa lại thành new_with_defaults() như trình biên dịch yêu cầu
             agent: Agent::new_with_defaults(),
+            cached_agent: cached_agent.build().into(),
             ddragon: client_builder.build()?,
             overview_cache: RefCell::new(LruCache::new(cache_size)),
             matchup_cache: RefCell::new(LruCache::new(cache_size)),
@@ -87,8 +118,17 @@ impl DataApi {
     }
 
     fn get_data<T: DeserializeOwned>(&self, url: &str) -> Result<T, UggError> {
+        Self::get_data_with(&self.agent, url)
+    }
+
+    /// Like [`DataApi::get_data`], but served from the disk cache after the first request.
+    fn get_cached_data<T: DeserializeOwned>(&self, url: &str) -> Result<T, UggError> {
+        Self::get_data_with(&self.cached_agent, url)
+    }
+
+    fn get_data_with<T: DeserializeOwned>(agent: &Agent, url: &str) -> Result<T, UggError> {
         simd_json::serde::from_reader::<ureq::BodyReader<'_>, T>(
-            self.agent
+            agent
                 .get(url)
                 .call()
                 .map_err(Box::new)?
@@ -110,6 +150,11 @@ impl DataApi {
         Ok(self.ddragon.champions()?.data)
     }
 
+    /// The full record of a champion, with its abilities and base stats, by Data Dragon id.
+    pub fn get_champion(&self, id: &str) -> Result<Champion, UggError> {
+        Ok(self.ddragon.champion(id)?)
+    }
+
     pub fn get_items(&self) -> Result<HashMap<String, Item>, UggError> {
         Ok(self.ddragon.items()?.data)
     }
@@ -158,10 +203,47 @@ impl DataApi {
         Ok(reduced_data)
     }
 
+    /// Fetches the stat shard rows from `CommunityDragon` for `version` (e.g. `14.23.1`).
+    pub fn get_stat_shards(&self, version: &str) -> Result<StatShards, UggError> {
+        let cdragon_version = version.split('.').take(2).collect::<Vec<_>>().join(".");
+        let base_url = format!(
+            "https://raw.communitydragon.org/{cdragon_version}/plugins/rcp-be-lol-game-data/global/default/v1"
+        );
+        let styles =
+            self.get_cached_data::<CDragonPerkStyles>(&format!("{base_url}/perkstyles.json"))?;
+        let perks = self.get_cached_data::<Vec<CDragonPerk>>(&format!("{base_url}/perks.json"))?;
+        Ok(StatShards::new(&styles, perks))
+    }
+
     pub fn get_ugg_api_versions(&self) -> Result<UggAPIVersions, UggError> {
         self.get_data::<UggAPIVersions>("https://static.bigbrain.gg/assets/lol/riot_patch_update/prod/ugg/ugg-api-versions.json")
     }
 
+    /// The roles u.gg reports each champion is played in, keyed by champion key.
+    pub fn get_primary_roles(
+        &self,
+        patch: &str,
+        api_versions: &UggAPIVersions,
+    ) -> Result<HashMap<String, Vec<mappings::Role>>, UggError> {
+        let api_version = api_versions
+            .get(patch)
+            .and_then(|versions| versions.get("primary_roles"))
+            .map_or("1.5.0", String::as_str);
+        let roles = self.get_cached_data::<HashMap<String, Vec<i32>>>(&format!(
+            "https://stats2.u.gg/lol/1.5/primary_roles/{patch}/{api_version}.json"
+        ))?;
+        Ok(roles
+            .into_iter()
+            .map(|(key, ids)| {
+                let roles = ids
+                    .into_iter()
+                    .filter_map(|id| mappings::Role::try_from(id).ok())
+                    .collect();
+                (key, roles)
+            })
+            .collect())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn get_stats(
         &self,
@@ -171,6 +253,7 @@ impl DataApi {
         region: mappings::Region,
         mode: mappings::Mode,
         build: mappings::Build,
+        rank: Option<Rank>,
         api_versions: &HashMap<String, HashMap<String, String>>,
     ) -> Result<(Overview, mappings::Role), UggError> {
         let api_version =
@@ -204,14 +287,17 @@ impl DataApi {
             c.put(sha256(&cache_path), stats_data.clone());
         }
 
-        let data_by_role = Rank::preferred_order()
-            .iter()
-            .find_map(|rank| {
-                stats_data
-                    .get(&region)
-                    .and_then(|region_data| region_data.get(rank))
-            })
+        // A requested rank must have data, otherwise use the broadest data available.
+        let region_data = stats_data
+            .get(&region)
             .ok_or(UggError::MissingRegionOrRank)?;
+        let data_by_role = match rank {
+            Some(rank) => region_data.get(&rank).ok_or(UggError::MissingRank(rank))?,
+            None => Rank::preferred_order()
+                .iter()
+                .find_map(|rank| region_data.get(rank))
+                .ok_or(UggError::MissingRegionOrRank)?,
+        };
 
         data_by_role
             .get_key_value(&role)
@@ -226,6 +312,7 @@ impl DataApi {
             .ok_or(UggError::MissingRole)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn get_matchups(
         &self,
         patch: &str,
@@ -233,6 +320,7 @@ impl DataApi {
         role: mappings::Role,
         region: mappings::Region,
         mode: mappings::Mode,
+        rank: Option<Rank>,
         api_versions: &HashMap<String, HashMap<String, String>>,
     ) -> Result<(MatchupData, mappings::Role), UggError> {
         let api_version =
@@ -263,14 +351,21 @@ impl DataApi {
             ))
         }?;
 
-        let data_by_role = Rank::preferred_order()
-            .iter()
-            .find_map(|rank| {
-                matchup_data
-                    .get(&region)
-                    .and_then(|region_data| region_data.get(rank))
-            })
+        if let Ok(mut c) = self.matchup_cache.try_borrow_mut() {
+            c.put(sha256(&cache_path), matchup_data.clone());
+        }
+
+        // A requested rank must have data, otherwise use the broadest data available.
+        let region_data = matchup_data
+            .get(&region)
             .ok_or(UggError::MissingRegionOrRank)?;
+        let data_by_role = match rank {
+            Some(rank) => region_data.get(&rank).ok_or(UggError::MissingRank(rank))?,
+            None => Rank::preferred_order()
+                .iter()
+                .find_map(|rank| region_data.get(rank))
+                .ok_or(UggError::MissingRegionOrRank)?,
+        };
 
         data_by_role
             .get_key_value(&role)
@@ -321,10 +416,22 @@ impl UggApi {
         let arena_augments = inner_api
             .get_arena_augments()
             .unwrap_or_else(|_| HashMap::new());
+        let stat_shards = inner_api
+            .get_stat_shards(&current_version)
+            .unwrap_or_else(|e| {
+                log::warn!("Could not load stat shards, using the built-in list: {e}");
+                StatShards::fallback()
+            });
 
         let mut patch_version_split = current_version.split('.').collect::<Vec<&str>>();
         patch_version_split.remove(patch_version_split.len() - 1);
         let patch_version = patch_version_split.join("_");
+        let primary_roles = inner_api
+            .get_primary_roles(&patch_version, &ugg_api_versions)
+            .unwrap_or_else(|e| {
+                log::warn!("Could not load champion roles, ignoring @role filters: {e}");
+                HashMap::new()
+            });
 
         Ok(Self {
             api: inner_api,
@@ -337,37 +444,38 @@ impl UggApi {
             runes,
             summoner_spells,
             arena_augments,
+            stat_shards,
+            primary_roles,
         })
     }
 
+    /// Looks a champion up by Data Dragon id, nickname or (partial/fuzzy) name, falling back
+    /// to the closest name by edit distance for typos.
     pub fn find_champ(&self, name: &str) -> &ChampionShort {
-        if self.champ_data.contains_key(name) {
-            &self.champ_data[name]
-        } else {
-            let mut lowest_distance = usize::MAX;
-            let mut closest_champ: &ChampionShort = &self.champ_data["Annie"];
-
-            let mut substring_lowest_dist = usize::MAX;
-            let mut substring_closest_champ: Option<&ChampionShort> = None;
-
-            for value in self.champ_data.values() {
-                let query_compare = name.to_ascii_lowercase();
-                let champ_compare = value.name.to_ascii_lowercase();
-                // Prefer matches where search query is an exact starting substring
-                let distance = levenshtein(query_compare.as_str(), champ_compare.as_str());
-                if champ_compare.starts_with(&query_compare) {
-                    if distance <= substring_lowest_dist {
-                        substring_lowest_dist = distance;
-                        substring_closest_champ = Some(value);
-                    }
-                } else if distance <= lowest_distance {
-                    lowest_distance = distance;
-                    closest_champ = value;
-                }
-            }
-
-            substring_closest_champ.unwrap_or(closest_champ)
+        if let Some(champ) = self.champ_data.get(name) {
+            return champ;
         }
+
+        let query = ChampionQuery::new(name);
+        let best_match = self
+            .champ_data
+            .values()
+            .filter_map(|c| query.score(&c.name, &c.id).map(|score| (score, c)))
+            // Break ties by name, since the map is unordered.
+            .max_by(|(a, a_champ), (b, b_champ)| a.cmp(b).then(b_champ.name.cmp(&a_champ.name)))
+            .map(|(_, c)| c);
+
+        best_match.unwrap_or_else(|| {
+            let query = normalize(name);
+            self.champ_data
+                .values()
+                .min_by_key(|c| (levenshtein(&query, &normalize(&c.name)), c.name.clone()))
+                .unwrap_or(&self.champ_data["Annie"])
+        })
+    }
+
+    pub fn get_champion(&self, champ: &ChampionShort) -> Result<Champion, UggError> {
+        self.api.get_champion(&champ.id)
     }
 
     pub fn get_stats(
@@ -377,6 +485,7 @@ impl UggApi {
         region: mappings::Region,
         mode: mappings::Mode,
         build: mappings::Build,
+        rank: Option<Rank>,
     ) -> Result<(Overview, mappings::Role), UggError> {
         self.api.get_stats(
             &self.patch_version,
@@ -385,6 +494,7 @@ impl UggApi {
             region,
             mode,
             build,
+            rank,
             &self.api_versions,
         )
     }
@@ -395,6 +505,7 @@ impl UggApi {
         role: mappings::Role,
         region: mappings::Region,
         mode: mappings::Mode,
+        rank: Option<Rank>,
     ) -> Result<(MatchupData, mappings::Role), UggError> {
         self.api.get_matchups(
             &self.patch_version,
@@ -402,6 +513,7 @@ impl UggApi {
             role,
             region,
             mode,
+            rank,
             &self.api_versions,
         )
     }
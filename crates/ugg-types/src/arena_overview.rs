@@ -4,7 +4,7 @@ use serde::Serialize;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 
 use crate::default_overview::{Abilities, Items, LateItem};
-use crate::overview::handle_unknown;
+use crate::overview::{handle_unknown, winrate};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ArenaOverviewData {
@@ -32,12 +32,8 @@ pub struct PrismaticItem {
 
 impl PrismaticItem {
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub fn winrate(&self) -> f64 {
-        if self.matches <= 0 {
-            return 0f64;
-        }
-        (self.wins as f64) / (self.matches as f64)
+        winrate(self.wins, self.matches)
     }
 }
 
@@ -105,6 +101,11 @@ impl ChampionSynergy {
         }
         (self.top_four as f64) / (self.picked as f64)
     }
+
+    #[must_use]
+    pub fn games(&self) -> i64 {
+        self.picked
+    }
 }
 
 impl PartialOrd for ChampionSynergy {
@@ -166,12 +167,23 @@ pub struct Augment {
 
 impl Augment {
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub fn winrate(&self) -> f64 {
-        if self.matches <= 0 {
+        winrate(self.wins, self.matches)
+    }
+
+    #[must_use]
+    pub fn games(&self) -> i64 {
+        self.matches
+    }
+
+    /// The share of `total_matches` in which this augment was picked.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pick_rate(&self, total_matches: i64) -> f64 {
+        if total_matches <= 0 {
             return 0f64;
         }
-        (self.wins as f64) / (self.matches as f64)
+        (self.matches as f64) / (total_matches as f64)
     }
 }
 
@@ -321,3 +333,27 @@ impl<'de> Deserialize<'de> for ArenaOverviewData {
         deserializer.deserialize_seq(ArenaOverviewDataVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synergy_rates_from_array() {
+        let synergy: ChampionSynergy = serde_json::from_str("[157, 60, 100, 20, 300]").unwrap();
+
+        assert_eq!(synergy.id, 157);
+        assert_eq!(synergy.games(), 100);
+        assert!((synergy.top_four_rate() - 0.6).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn augment_pick_rate_is_relative_to_champion_games() {
+        let augment: Augment = serde_json::from_str("[12, 30, 50]").unwrap();
+
+        assert_eq!(augment.games(), 50);
+        assert!((augment.winrate() - 0.6).abs() < f64::EPSILON);
+        assert!((augment.pick_rate(200) - 0.25).abs() < f64::EPSILON);
+        assert!(augment.pick_rate(0).abs() < f64::EPSILON);
+    }
+}
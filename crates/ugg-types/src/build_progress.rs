@@ -0,0 +1,143 @@
+use crate::overview::Overview;
+
+/// How far along the recommended build a set of owned items is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildProgress {
+    /// Every item in the recommended path, with whether it's already owned. Late item slots
+    /// contribute the owned option if there is one, otherwise their most popular option.
+    pub path: Vec<(i64, bool)>,
+    /// The first item in the path that isn't owned yet.
+    pub next: Option<i64>,
+}
+
+impl BuildProgress {
+    #[must_use]
+    pub fn new(overview: &Overview, owned_item_ids: &[i64]) -> Self {
+        let mut remaining = owned_item_ids.to_vec();
+        let mut take_owned = |id: i64| {
+            remaining
+                .iter()
+                .position(|owned| *owned == id)
+                .map(|pos| remaining.swap_remove(pos))
+                .is_some()
+        };
+
+        let mut path = overview
+            .core_items()
+            .item_ids
+            .iter()
+            .map(|id| (*id, take_owned(*id)))
+            .collect::<Vec<_>>();
+
+        for options in overview.late_item_options() {
+            if let Some(owned) = options.iter().find(|o| take_owned(o.id)) {
+                path.push((owned.id, true));
+            } else if let Some(first) = options.first() {
+                path.push((first.id, false));
+            }
+        }
+
+        let next = path.iter().find(|(_, owned)| !owned).map(|(id, _)| *id);
+        Self { path, next }
+    }
+
+    #[must_use]
+    pub fn completed(&self) -> usize {
+        self.path.iter().filter(|(_, owned)| *owned).count()
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.path.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default_overview::{
+        Abilities, Items, LateItem, OverviewData, Runes, Shards, SummonerSpells,
+    };
+
+    fn late_items(ids: &[i64]) -> Vec<LateItem> {
+        ids.iter()
+            .map(|id| LateItem {
+                matches: 0,
+                wins: 0,
+                id: *id,
+            })
+            .collect()
+    }
+
+    fn build_overview(core: &[i64], item_4: &[i64], item_5: &[i64]) -> Overview {
+        Overview::Default(OverviewData {
+            runes: Runes {
+                matches: 0,
+                wins: 0,
+                primary_style_id: 0,
+                secondary_style_id: 0,
+                rune_ids: vec![],
+            },
+            summoner_spells: SummonerSpells {
+                matches: 0,
+                wins: 0,
+                spell_ids: vec![],
+            },
+            starting_items: Items {
+                matches: 0,
+                wins: 0,
+                item_ids: vec![],
+            },
+            core_items: Items {
+                matches: 0,
+                wins: 0,
+                item_ids: core.to_vec(),
+            },
+            abilities: Abilities {
+                matches: 0,
+                wins: 0,
+                ability_order: vec![],
+                ability_max_order: String::new(),
+            },
+            item_4_options: late_items(item_4),
+            item_5_options: late_items(item_5),
+            item_6_options: vec![],
+            wins: 0,
+            matches: 0,
+            low_sample_size: false,
+            shards: Shards::default(),
+        })
+    }
+
+    #[test]
+    fn nothing_owned_recommends_first_core_item() {
+        let progress = BuildProgress::new(&build_overview(&[1, 2, 3], &[4, 5], &[6]), &[]);
+
+        assert_eq!(progress.next, Some(1));
+        assert_eq!(progress.completed(), 0);
+        assert_eq!(progress.total(), 5);
+    }
+
+    #[test]
+    fn owned_late_option_counts_for_its_slot() {
+        let progress = BuildProgress::new(
+            &build_overview(&[1, 2, 3], &[4, 5], &[6, 7]),
+            &[3, 1, 2, 5, 1001],
+        );
+
+        assert_eq!(
+            progress.path,
+            vec![(1, true), (2, true), (3, true), (5, true), (6, false)]
+        );
+        assert_eq!(progress.next, Some(6));
+        assert_eq!(progress.completed(), 4);
+    }
+
+    #[test]
+    fn an_item_only_fills_one_slot() {
+        let progress = BuildProgress::new(&build_overview(&[1], &[1, 2], &[]), &[1]);
+
+        assert_eq!(progress.path, vec![(1, true), (1, false)]);
+        assert_eq!(progress.next, Some(1));
+    }
+}
@@ -0,0 +1,333 @@
+use crate::mappings::Role;
+
+/// Common nicknames, mapped to the Data Dragon id of the champion they refer to.
+pub const ALIASES: [(&str, &str); 34] = [
+    ("mf", "MissFortune"),
+    ("tf", "TwistedFate"),
+    ("j4", "JarvanIV"),
+    ("jarvan", "JarvanIV"),
+    ("wukong", "MonkeyKing"),
+    ("asol", "AurelionSol"),
+    ("gp", "Gangplank"),
+    ("lb", "Leblanc"),
+    ("tk", "TahmKench"),
+    ("tahm", "TahmKench"),
+    ("xin", "XinZhao"),
+    ("yi", "MasterYi"),
+    ("ww", "Warwick"),
+    ("cass", "Cassiopeia"),
+    ("kass", "Kassadin"),
+    ("heimer", "Heimerdinger"),
+    ("mundo", "DrMundo"),
+    ("fiddle", "Fiddlesticks"),
+    ("naut", "Nautilus"),
+    ("voli", "Volibear"),
+    ("vlad", "Vladimir"),
+    ("malph", "Malphite"),
+    ("morg", "Morgana"),
+    ("ez", "Ezreal"),
+    ("cait", "Caitlyn"),
+    ("blitz", "Blitzcrank"),
+    ("kha", "Khazix"),
+    ("cho", "Chogath"),
+    ("trist", "Tristana"),
+    ("trynd", "Tryndamere"),
+    ("hec", "Hecarim"),
+    ("mord", "Mordekaiser"),
+    ("kat", "Katarina"),
+    ("sej", "Sejuani"),
+];
+
+/// Lowercases, folds accents and drops everything but letters and digits, so `Kai'Sa`,
+/// `kai sa` and `KAISA` compare equal.
+#[must_use]
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            c => c,
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+/// Score of a query spelling out a champion's name or id.
+const EXACT_SCORE: u32 = 900;
+/// Score of a nickname from [`ALIASES`], above any spelled out match.
+const ALIAS_SCORE: u32 = 1000;
+
+/// Scores `query` against `target`, both normalized. Whole matches beat prefixes, which beat
+/// substrings, which beat scattered subsequences.
+fn score_normalized(query: &str, target: &str) -> Option<u32> {
+    let length_penalty = u32::try_from(target.len().saturating_sub(query.len())).unwrap_or(99);
+    if target == query {
+        return Some(EXACT_SCORE);
+    }
+    if target.starts_with(query) {
+        return Some(800u32.saturating_sub(length_penalty).max(700));
+    }
+    if let Some(pos) = target.find(query) {
+        return Some(
+            600u32
+                .saturating_sub(u32::try_from(pos).unwrap_or(99))
+                .max(500),
+        );
+    }
+
+    // Subsequence: every query character appears in order. Reward consecutive runs and
+    // penalize gaps between matched characters.
+    let mut score = 300u32;
+    let mut target_chars = target.chars().enumerate();
+    let mut last_match: Option<usize> = None;
+    for q in query.chars() {
+        let (idx, _) = target_chars.find(|(_, t)| *t == q)?;
+        match last_match {
+            Some(last) if idx == last + 1 => score += 10,
+            Some(last) => score = score.saturating_sub(u32::try_from(idx - last).unwrap_or(99)),
+            None => score = score.saturating_sub(u32::try_from(idx).unwrap_or(99)),
+        }
+        last_match = Some(idx);
+    }
+    Some(score.clamp(1, 499))
+}
+
+/// A champion search query, normalized once and scored against many champions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChampionQuery {
+    normalized: String,
+}
+
+impl ChampionQuery {
+    #[must_use]
+    pub fn new(query: &str) -> Self {
+        Self {
+            normalized: normalize(query),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.normalized.is_empty()
+    }
+
+    /// How well the query matches a champion by display name (`Wukong`) or Data Dragon id
+    /// (`MonkeyKing`), higher is better. `None` if it doesn't match at all; an empty query
+    /// matches everything equally.
+    #[must_use]
+    pub fn score(&self, name: &str, id: &str) -> Option<u32> {
+        if self.is_empty() {
+            return Some(0);
+        }
+
+        let id = normalize(id);
+        if ALIASES
+            .iter()
+            .any(|(alias, target)| *alias == self.normalized && normalize(target) == id)
+        {
+            return Some(ALIAS_SCORE);
+        }
+
+        [normalize(name), id]
+            .iter()
+            .filter_map(|target| score_normalized(&self.normalized, target))
+            .max()
+    }
+
+    /// Whether the query names the champion outright, by name, id or nickname, rather than just
+    /// partially matching it.
+    #[must_use]
+    pub fn is_exact(&self, name: &str, id: &str) -> bool {
+        !self.is_empty()
+            && self
+                .score(name, id)
+                .is_some_and(|score| score >= EXACT_SCORE)
+    }
+}
+
+/// Role names accepted after `@`, matched by prefix so `@jun` works while typing.
+const ROLE_NAMES: [(&str, Role); 10] = [
+    ("top", Role::Top),
+    ("jungle", Role::Jungle),
+    ("jg", Role::Jungle),
+    ("mid", Role::Mid),
+    ("middle", Role::Mid),
+    ("adc", Role::ADCarry),
+    ("bot", Role::ADCarry),
+    ("bottom", Role::ADCarry),
+    ("support", Role::Support),
+    ("sup", Role::Support),
+];
+
+/// A search box input split into a name query and filters: `#tank` narrows by class tag and
+/// `@jungle` by the roles a champion is played in, e.g. `#mage @mid an`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChampionFilter {
+    pub query: ChampionQuery,
+    /// Lowercased tag prefixes, all of which have to match.
+    pub tags: Vec<String>,
+    /// One set of candidate roles per `@` filter, each of which has to match.
+    pub roles: Vec<Vec<Role>>,
+}
+
+impl ChampionFilter {
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        let mut tags = Vec::new();
+        let mut roles = Vec::new();
+        let mut query = Vec::new();
+        for token in input.split_whitespace() {
+            if let Some(tag) = token.strip_prefix('#') {
+                if !tag.is_empty() {
+                    tags.push(tag.to_lowercase());
+                }
+            } else if let Some(role) = token.strip_prefix('@') {
+                if !role.is_empty() {
+                    let role = role.to_lowercase();
+                    let mut candidates = ROLE_NAMES
+                        .iter()
+                        .filter(|(name, _)| name.starts_with(&role))
+                        .map(|(_, role)| *role)
+                        .collect::<Vec<_>>();
+                    candidates.dedup();
+                    roles.push(candidates);
+                }
+            } else {
+                query.push(token);
+            }
+        }
+
+        Self {
+            query: ChampionQuery::new(&query.join(" ")),
+            tags,
+            roles,
+        }
+    }
+
+    #[must_use]
+    pub fn has_filters(&self) -> bool {
+        !self.tags.is_empty() || !self.roles.is_empty()
+    }
+
+    /// Scores a champion like [`ChampionQuery::score`], or `None` if it's filtered out by
+    /// its class `tags` (e.g. `Tank`) or the `roles` it's played in.
+    #[must_use]
+    pub fn score(
+        &self,
+        name: &str,
+        id: &str,
+        tags: &[impl AsRef<str>],
+        roles: &[Role],
+    ) -> Option<u32> {
+        let tags_match = self.tags.iter().all(|wanted| {
+            tags.iter()
+                .any(|tag| tag.as_ref().to_lowercase().starts_with(wanted.as_str()))
+        });
+        let roles_match = self
+            .roles
+            .iter()
+            .all(|candidates| candidates.iter().any(|role| roles.contains(role)));
+        if tags_match && roles_match {
+            self.query.score(name, id)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_folds_punctuation_and_accents() {
+        assert_eq!(normalize("Kai'Sa"), "kaisa");
+        assert_eq!(normalize("kai sa"), "kaisa");
+        assert_eq!(normalize("Nunu & Willump"), "nunuwillump");
+        assert_eq!(normalize("Dr. Mündo"), "drmundo");
+    }
+
+    #[test]
+    fn aliases_match_best() {
+        let query = ChampionQuery::new("mf");
+        assert_eq!(query.score("Miss Fortune", "MissFortune"), Some(1000));
+        assert_eq!(
+            ChampionQuery::new("Wukong").score("Wukong", "MonkeyKing"),
+            Some(1000)
+        );
+        assert_eq!(
+            ChampionQuery::new("monkeyking").score("Wukong", "MonkeyKing"),
+            Some(900)
+        );
+    }
+
+    #[test]
+    fn ranks_prefix_over_substring_over_subsequence() {
+        let query = ChampionQuery::new("kog");
+        let prefix = query.score("Kog'Maw", "KogMaw").unwrap();
+        let subsequence = ChampionQuery::new("kgmw")
+            .score("Kog'Maw", "KogMaw")
+            .unwrap();
+        let substring = ChampionQuery::new("maw")
+            .score("Kog'Maw", "KogMaw")
+            .unwrap();
+
+        assert!(prefix > substring);
+        assert!(substring > subsequence);
+        assert_eq!(query.score("Ahri", "Ahri"), None);
+    }
+
+    #[test]
+    fn exact_matches_need_the_whole_name_or_a_nickname() {
+        assert!(ChampionQuery::new("kai'sa").is_exact("Kai'Sa", "Kaisa"));
+        assert!(ChampionQuery::new("monkeyking").is_exact("Wukong", "MonkeyKing"));
+        assert!(ChampionQuery::new("mf").is_exact("Miss Fortune", "MissFortune"));
+        assert!(!ChampionQuery::new("kai").is_exact("Kai'Sa", "Kaisa"));
+        assert!(!ChampionQuery::new("").is_exact("Ahri", "Ahri"));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(ChampionQuery::new(" ").score("Ahri", "Ahri"), Some(0));
+    }
+
+    #[test]
+    fn filter_parses_tags_roles_and_query() {
+        let filter = ChampionFilter::parse("#Tank @jun  sej");
+
+        assert_eq!(filter.tags, vec!["tank".to_string()]);
+        assert_eq!(filter.roles, vec![vec![Role::Jungle]]);
+        assert_eq!(filter.query, ChampionQuery::new("sej"));
+        assert!(filter.has_filters());
+    }
+
+    #[test]
+    fn filter_narrows_by_tags_and_roles() {
+        let tags = ["Tank".to_string(), "Fighter".to_string()];
+        let filter = ChampionFilter::parse("#tank @jungle");
+
+        assert_eq!(
+            filter.score("Sejuani", "Sejuani", &tags, &[Role::Jungle]),
+            Some(0)
+        );
+        assert_eq!(
+            filter.score("Sejuani", "Sejuani", &tags, &[Role::Top]),
+            None
+        );
+        assert_eq!(
+            ChampionFilter::parse("#mage").score("Sejuani", "Sejuani", &tags, &[Role::Jungle]),
+            None
+        );
+        assert_eq!(
+            ChampionFilter::parse("@xyz").score("Sejuani", "Sejuani", &tags, &[Role::Jungle]),
+            None
+        );
+    }
+}
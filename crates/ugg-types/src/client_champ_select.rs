@@ -1,15 +1,217 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::mappings::Role;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChampSelectSession {
     pub local_player_cell_id: i64,
     pub my_team: Vec<TeamMember>,
+    #[serde(default)]
+    pub their_team: Vec<TeamMember>,
+    #[serde(default)]
+    pub actions: Vec<Vec<ChampSelectAction>>,
+    #[serde(default)]
+    pub bans: ChampSelectBans,
+}
+
+impl ChampSelectSession {
+    #[must_use]
+    pub fn me(&self) -> Option<&TeamMember> {
+        self.my_team
+            .iter()
+            .find(|p| p.cell_id == self.local_player_cell_id)
+    }
+
+    /// The role the client assigned us, if the queue has position assignment.
+    #[must_use]
+    pub fn assigned_role(&self) -> Option<Role> {
+        self.me()
+            .and_then(|me| Role::from_client_position(&me.assigned_position))
+    }
+
+    /// Champions the enemy team has picked so far.
+    #[must_use]
+    pub fn enemy_champion_ids(&self) -> Vec<i64> {
+        self.their_team
+            .iter()
+            .map(|m| m.champion_id)
+            .filter(|id| *id > 0)
+            .collect()
+    }
+
+    /// The enemy picked into our assigned position, when the client reveals positions.
+    #[must_use]
+    pub fn enemy_laner_id(&self) -> Option<i64> {
+        let position = &self.me()?.assigned_position;
+        self.their_team
+            .iter()
+            .find(|m| !position.is_empty() && m.assigned_position == *position)
+            .map(|m| m.champion_id)
+            .filter(|id| *id > 0)
+    }
+
+    /// We still have a ban to make.
+    #[must_use]
+    pub fn is_ban_phase(&self) -> bool {
+        self.actions.iter().flatten().any(|a| {
+            a.action_type == "ban" && a.actor_cell_id == self.local_player_cell_id && !a.completed
+        })
+    }
+
+    /// We've locked in our pick.
+    #[must_use]
+    pub fn is_locked_in(&self) -> bool {
+        self.actions.iter().flatten().any(|a| {
+            a.action_type == "pick" && a.actor_cell_id == self.local_player_cell_id && a.completed
+        })
+    }
+
+    /// The champion a teammate has picked or is hovering. In Arena this is our duo partner.
+    #[must_use]
+    pub fn teammate_champion_id(&self) -> Option<i64> {
+        self.my_team
+            .iter()
+            .filter(|m| m.cell_id != self.local_player_cell_id)
+            .map(|m| {
+                if m.champion_id > 0 {
+                    m.champion_id
+                } else {
+                    m.champion_pick_intent
+                }
+            })
+            .find(|id| *id > 0)
+    }
+
+    /// Champions that are banned, picked or declared as a pick intent by anyone in the lobby.
+    #[must_use]
+    pub fn unavailable_champion_ids(&self) -> Vec<i64> {
+        let banned = self
+            .bans
+            .my_team_bans
+            .iter()
+            .chain(self.bans.their_team_bans.iter())
+            .copied();
+        let acted = self
+            .actions
+            .iter()
+            .flatten()
+            .filter(|a| a.completed || a.action_type == "pick")
+            .map(|a| a.champion_id);
+        let members = self
+            .my_team
+            .iter()
+            .chain(self.their_team.iter())
+            .flat_map(|m| [m.champion_id, m.champion_pick_intent]);
+
+        let mut ids = banned
+            .chain(acted)
+            .chain(members)
+            .filter(|id| *id > 0)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamMember {
     pub cell_id: i64,
     pub champion_id: i64,
+    #[serde(default)]
+    pub champion_pick_intent: i64,
+    #[serde(default)]
+    pub assigned_position: String,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChampSelectAction {
+    pub actor_cell_id: i64,
+    pub champion_id: i64,
+    pub completed: bool,
+    pub is_in_progress: bool,
+    #[serde(rename = "type")]
+    pub action_type: String,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChampSelectBans {
+    pub my_team_bans: Vec<i64>,
+    pub their_team_bans: Vec<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_session() -> ChampSelectSession {
+        serde_json::from_str(
+            r#"{
+                "localPlayerCellId": 1,
+                "myTeam": [
+                    {"cellId": 0, "championId": 0, "championPickIntent": 86, "assignedPosition": "top"},
+                    {"cellId": 1, "championId": 0, "championPickIntent": 0, "assignedPosition": "middle"}
+                ],
+                "theirTeam": [
+                    {"cellId": 5, "championId": 0, "assignedPosition": "top"},
+                    {"cellId": 6, "championId": 103, "assignedPosition": "middle"}
+                ],
+                "actions": [[
+                    {"actorCellId": 0, "championId": 122, "completed": true, "isInProgress": false, "type": "ban"},
+                    {"actorCellId": 1, "championId": 238, "completed": false, "isInProgress": true, "type": "ban"}
+                ]],
+                "bans": {"myTeamBans": [], "theirTeamBans": [157]}
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn local_player_is_found() {
+        let session = build_session();
+
+        assert_eq!(session.me().map(|m| m.cell_id), Some(1));
+        assert_eq!(session.assigned_role(), Some(Role::Mid));
+        assert!(session.is_ban_phase());
+        assert!(!session.is_locked_in());
+    }
+
+    #[test]
+    fn teammate_pick_intent_is_used() {
+        assert_eq!(build_session().teammate_champion_id(), Some(86));
+    }
+
+    #[test]
+    fn hovered_bans_are_still_available() {
+        assert_eq!(
+            build_session().unavailable_champion_ids(),
+            vec![86, 103, 122, 157]
+        );
+    }
+
+    #[test]
+    fn enemy_laner_matches_our_position() {
+        let session = build_session();
+
+        assert_eq!(session.enemy_champion_ids(), vec![103]);
+        assert_eq!(session.enemy_laner_id(), Some(103));
+    }
+
+    #[test]
+    fn minimal_session_still_parses() {
+        let session: ChampSelectSession = serde_json::from_str(
+            r#"{"localPlayerCellId": 0, "myTeam": [{"cellId": 0, "championId": 266}]}"#,
+        )
+        .unwrap();
+
+        assert!(!session.is_ban_phase());
+        assert_eq!(session.assigned_role(), None);
+        assert_eq!(session.unavailable_champion_ids(), vec![266]);
+        assert_eq!(session.enemy_laner_id(), None);
+        assert_eq!(session.teammate_champion_id(), None);
+    }
 }
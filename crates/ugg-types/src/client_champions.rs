@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+pub type ClientChampions = Vec<ClientChampion>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClientChampion {
+    pub id: i64,
+    pub name: String,
+    pub alias: String,
+    pub free_to_play: bool,
+    pub ownership: ChampionOwnership,
+}
+
+impl ClientChampion {
+    /// The champion can be picked, either because it's owned or it's in the free rotation.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        self.ownership.owned || self.free_to_play || self.ownership.free_to_play_reward
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChampionOwnership {
+    pub owned: bool,
+    pub free_to_play_reward: bool,
+}
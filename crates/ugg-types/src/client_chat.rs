@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+const CHAMP_SELECT_CONVERSATION: &str = "championSelect";
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChatConversation {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub conversation_type: String,
+}
+
+impl ChatConversation {
+    #[must_use]
+    pub fn is_champ_select(&self) -> bool {
+        self.conversation_type == CHAMP_SELECT_CONVERSATION
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewChatMessage {
+    pub body: String,
+    #[serde(rename = "type")]
+    pub message_type: String,
+}
+
+impl NewChatMessage {
+    #[must_use]
+    pub fn new(body: &str) -> Self {
+        Self {
+            body: body.to_string(),
+            message_type: "chat".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_champ_select_conversation() {
+        let conversations: Vec<ChatConversation> = serde_json::from_str(
+            r#"[
+                {"id": "abc@pvp.net", "type": "chat", "unreadMessageCount": 0},
+                {"id": "123@champ-select.pvp.net", "type": "championSelect"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            conversations
+                .iter()
+                .find(|c| c.is_champ_select())
+                .map(|c| c.id.as_str()),
+            Some("123@champ-select.pvp.net")
+        );
+    }
+
+    #[test]
+    fn new_message_serializes_as_chat() {
+        assert_eq!(
+            serde_json::to_string(&NewChatMessage::new("hi")).unwrap(),
+            r#"{"body":"hi","type":"chat"}"#
+        );
+    }
+}
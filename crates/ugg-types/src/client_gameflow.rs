@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameflowPhase {
+    #[default]
+    None,
+    Lobby,
+    Matchmaking,
+    CheckedIntoTournament,
+    ReadyCheck,
+    ChampSelect,
+    GameStart,
+    FailedToLaunch,
+    InProgress,
+    Reconnect,
+    WaitingForStats,
+    PreEndOfGame,
+    EndOfGame,
+    TerminatedInError,
+    #[serde(other)]
+    Unknown,
+}
+
+impl GameflowPhase {
+    /// The game has been launched (or is being reconnected to).
+    #[must_use]
+    pub const fn is_in_game(self) -> bool {
+        matches!(self, Self::GameStart | Self::InProgress | Self::Reconnect)
+    }
+
+    /// The game is over and the client is showing (or waiting for) the end of game screen.
+    #[must_use]
+    pub const fn is_post_game(self) -> bool {
+        matches!(
+            self,
+            Self::WaitingForStats | Self::PreEndOfGame | Self::EndOfGame
+        )
+    }
+}
+
+impl Display for GameflowPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let phase_str = match self {
+            Self::None => "Idle",
+            Self::Lobby => "Lobby",
+            Self::Matchmaking => "Matchmaking",
+            Self::CheckedIntoTournament => "Tournament",
+            Self::ReadyCheck => "Ready Check",
+            Self::ChampSelect => "Champ Select",
+            Self::GameStart => "Game Start",
+            Self::FailedToLaunch => "Failed To Launch",
+            Self::InProgress => "In Game",
+            Self::Reconnect => "Reconnect",
+            Self::WaitingForStats => "Waiting For Stats",
+            Self::PreEndOfGame => "Pre End Of Game",
+            Self::EndOfGame => "End Of Game",
+            Self::TerminatedInError => "Terminated",
+            Self::Unknown => "Unknown",
+        };
+        write!(f, "{phase_str}")
+    }
+}
@@ -0,0 +1,45 @@
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+pub type ChampionMasteries = Vec<ChampionMastery>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChampionMastery {
+    pub champion_id: i64,
+    pub champion_level: i64,
+    pub champion_points: i64,
+}
+
+/// Ids of the `count` champions with the most mastery points, which is the closest the client
+/// gets to "most played".
+#[must_use]
+pub fn most_played(masteries: &[ChampionMastery], count: usize) -> Vec<i64> {
+    let mut masteries = masteries.iter().collect::<Vec<_>>();
+    masteries.sort_by_key(|m| Reverse(m.champion_points));
+    masteries
+        .into_iter()
+        .take(count)
+        .map(|m| m.champion_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_played_ranks_by_points() {
+        let masteries: ChampionMasteries = serde_json::from_str(
+            r#"[
+                {"championId": 103, "championLevel": 7, "championPoints": 120000, "puuid": "x"},
+                {"championId": 122, "championLevel": 5, "championPoints": 30000},
+                {"championId": 266, "championLevel": 7, "championPoints": 250000}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(most_played(&masteries, 2), vec![266, 103]);
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::mappings::Rank;
+
+const SOLO_QUEUE: &str = "RANKED_SOLO_5x5";
+const FLEX_QUEUE: &str = "RANKED_FLEX_SR";
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RankedStats {
+    pub queue_map: HashMap<String, RankedQueueStats>,
+}
+
+impl RankedStats {
+    #[must_use]
+    pub fn solo(&self) -> Option<&RankedQueueStats> {
+        self.queue_map.get(SOLO_QUEUE)
+    }
+
+    #[must_use]
+    pub fn flex(&self) -> Option<&RankedQueueStats> {
+        self.queue_map.get(FLEX_QUEUE)
+    }
+
+    /// The u.gg rank matching our solo queue tier, or flex if we're unranked in solo.
+    #[must_use]
+    pub fn ugg_rank(&self) -> Option<Rank> {
+        [self.solo(), self.flex()]
+            .into_iter()
+            .flatten()
+            .find_map(|q| Rank::from_client_tier(&q.tier))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RankedQueueStats {
+    pub tier: String,
+    pub division: String,
+    pub league_points: i64,
+    pub wins: i64,
+    pub losses: i64,
+}
+
+impl RankedQueueStats {
+    #[must_use]
+    pub fn is_ranked(&self) -> bool {
+        Rank::from_client_tier(&self.tier).is_some()
+    }
+}
+
+impl Display for RankedQueueStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.is_ranked() {
+            return write!(f, "Unranked");
+        }
+
+        let mut tier = self.tier.to_lowercase();
+        if let Some(first) = tier.get_mut(0..1) {
+            first.make_ascii_uppercase();
+        }
+        // Apex tiers have no divisions, the client reports "NA" for them.
+        if self.division.is_empty() || self.division == "NA" {
+            write!(f, "{tier} {}LP", self.league_points)
+        } else {
+            write!(f, "{tier} {} {}LP", self.division, self.league_points)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_stats() -> RankedStats {
+        serde_json::from_str(
+            r#"{
+                "queueMap": {
+                    "RANKED_SOLO_5x5": {"tier": "", "division": "NA", "leaguePoints": 0},
+                    "RANKED_FLEX_SR": {"tier": "PLATINUM", "division": "II", "leaguePoints": 45, "wins": 10, "losses": 8}
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn unranked_solo_falls_back_to_flex() {
+        assert_eq!(build_stats().ugg_rank(), Some(Rank::Platinum));
+    }
+
+    #[test]
+    fn queue_stats_display() {
+        let stats = build_stats();
+
+        assert_eq!(stats.solo().unwrap().to_string(), "Unranked");
+        assert_eq!(stats.flex().unwrap().to_string(), "Platinum II 45LP");
+    }
+}
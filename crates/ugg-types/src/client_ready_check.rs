@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadyCheckState {
+    #[default]
+    Invalid,
+    InProgress,
+    EveryoneReady,
+    StrangerNotReady,
+    PartyNotReady,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadyCheckResponse {
+    #[default]
+    None,
+    Accepted,
+    Declined,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReadyCheck {
+    pub state: ReadyCheckState,
+    pub player_response: ReadyCheckResponse,
+    /// Seconds since the ready check popped.
+    pub timer: f64,
+}
+
+impl ReadyCheck {
+    /// A match was found and we haven't accepted or declined it yet.
+    #[must_use]
+    pub fn needs_response(&self) -> bool {
+        self.state == ReadyCheckState::InProgress
+            && self.player_response == ReadyCheckResponse::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_ready_check_needs_response() {
+        let check: ReadyCheck = serde_json::from_str(
+            r#"{"state": "InProgress", "playerResponse": "None", "timer": 2.5, "declinerIds": []}"#,
+        )
+        .unwrap();
+
+        assert!(check.needs_response());
+    }
+
+    #[test]
+    fn answered_ready_check_does_not() {
+        let check: ReadyCheck =
+            serde_json::from_str(r#"{"state": "InProgress", "playerResponse": "Accepted"}"#)
+                .unwrap();
+
+        assert!(!check.needs_response());
+        assert!(!ReadyCheck::default().needs_response());
+    }
+}
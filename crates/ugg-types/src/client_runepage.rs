@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
+use crate::rune::RuneExtended;
+
 pub type RunePages = Vec<RunePage>;
 
 #[allow(clippy::struct_excessive_bools)]
@@ -28,3 +33,260 @@ pub struct NewRunePage {
     pub selected_perk_ids: Vec<i64>,
     pub sub_style_id: i64,
 }
+
+/// Why a set of rune IDs can't be turned into a valid rune page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunePageError {
+    MissingStyle,
+    SameStyle(i64),
+    UnknownRune(i64),
+    RuneNotInStyle { rune: i64, style: i64 },
+    KeystoneCount(usize),
+    PrimarySlotCoverage { slot: u64, count: usize },
+    SecondaryRuneCount(usize),
+    InvalidSecondarySlots,
+    ShardCount(usize),
+}
+
+impl Display for RunePageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingStyle => write!(f, "primary or secondary style is missing"),
+            Self::SameStyle(style) => {
+                write!(f, "primary and secondary style are both {style}")
+            }
+            Self::UnknownRune(rune) => write!(f, "rune {rune} does not exist"),
+            Self::RuneNotInStyle { rune, style } => {
+                write!(
+                    f,
+                    "rune {rune} belongs to style {style}, not the page's styles"
+                )
+            }
+            Self::KeystoneCount(count) => write!(f, "expected 1 keystone, found {count}"),
+            Self::PrimarySlotCoverage { slot, count } => {
+                write!(f, "expected 1 primary rune in slot {slot}, found {count}")
+            }
+            Self::SecondaryRuneCount(count) => {
+                write!(f, "expected 2 secondary runes, found {count}")
+            }
+            Self::InvalidSecondarySlots => write!(
+                f,
+                "secondary runes must come from two different non-keystone slots"
+            ),
+            Self::ShardCount(count) => write!(f, "expected 3 stat shards, found {count}"),
+        }
+    }
+}
+
+impl std::error::Error for RunePageError {}
+
+const PRIMARY_SLOTS: u64 = 4;
+const SHARD_COUNT: usize = 3;
+
+/// Builds a [`NewRunePage`] from rune IDs, checking them against the rune tree structure.
+pub struct RunePageBuilder<'a, T: Clone> {
+    runes: &'a HashMap<i64, RuneExtended<T>>,
+    name: String,
+    primary_style_id: i64,
+    sub_style_id: i64,
+    rune_ids: Vec<i64>,
+    shard_ids: Vec<i64>,
+}
+
+impl<'a, T: Clone> RunePageBuilder<'a, T> {
+    #[must_use]
+    pub fn new(runes: &'a HashMap<i64, RuneExtended<T>>) -> Self {
+        Self {
+            runes,
+            name: String::new(),
+            primary_style_id: 0,
+            sub_style_id: 0,
+            rune_ids: Vec::new(),
+            shard_ids: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn styles(mut self, primary_style_id: i64, sub_style_id: i64) -> Self {
+        self.primary_style_id = primary_style_id;
+        self.sub_style_id = sub_style_id;
+        self
+    }
+
+    #[must_use]
+    pub fn rune_ids(mut self, rune_ids: &[i64]) -> Self {
+        self.rune_ids = rune_ids.to_vec();
+        self
+    }
+
+    #[must_use]
+    pub fn shard_ids(mut self, shard_ids: &[i64]) -> Self {
+        self.shard_ids = shard_ids.to_vec();
+        self
+    }
+
+    fn runes_in_style(&self, style: i64) -> Vec<(i64, &'a RuneExtended<T>)> {
+        let mut runes = self
+            .rune_ids
+            .iter()
+            .filter_map(|id| self.runes.get(id).map(|r| (*id, r)))
+            .filter(|(_, r)| r.parent_id == style)
+            .collect::<Vec<_>>();
+        runes.sort_by_key(|(_, r)| r.slot);
+        runes
+    }
+
+    pub fn build(self) -> Result<NewRunePage, RunePageError> {
+        if self.primary_style_id <= 0 || self.sub_style_id <= 0 {
+            return Err(RunePageError::MissingStyle);
+        }
+        if self.primary_style_id == self.sub_style_id {
+            return Err(RunePageError::SameStyle(self.primary_style_id));
+        }
+
+        for id in &self.rune_ids {
+            let rune = self.runes.get(id).ok_or(RunePageError::UnknownRune(*id))?;
+            if rune.parent_id != self.primary_style_id && rune.parent_id != self.sub_style_id {
+                return Err(RunePageError::RuneNotInStyle {
+                    rune: *id,
+                    style: rune.parent_id,
+                });
+            }
+        }
+
+        let primary = self.runes_in_style(self.primary_style_id);
+        for slot in 0..PRIMARY_SLOTS {
+            let count = primary.iter().filter(|(_, r)| r.slot == slot).count();
+            match (slot, count) {
+                (_, 1) => {}
+                (0, count) => return Err(RunePageError::KeystoneCount(count)),
+                (slot, count) => return Err(RunePageError::PrimarySlotCoverage { slot, count }),
+            }
+        }
+
+        let secondary = self.runes_in_style(self.sub_style_id);
+        if secondary.len() != 2 {
+            return Err(RunePageError::SecondaryRuneCount(secondary.len()));
+        }
+        if secondary[0].1.slot == 0 || secondary[0].1.slot == secondary[1].1.slot {
+            return Err(RunePageError::InvalidSecondarySlots);
+        }
+
+        if self.shard_ids.len() != SHARD_COUNT {
+            return Err(RunePageError::ShardCount(self.shard_ids.len()));
+        }
+
+        Ok(NewRunePage {
+            name: self.name,
+            primary_style_id: self.primary_style_id,
+            sub_style_id: self.sub_style_id,
+            selected_perk_ids: primary
+                .iter()
+                .chain(secondary.iter())
+                .map(|(id, _)| *id)
+                .chain(self.shard_ids)
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: i64 = 8000;
+    const DOMINATION: i64 = 8100;
+
+    fn rune(slot: u64, parent_id: i64) -> RuneExtended<()> {
+        RuneExtended {
+            slot,
+            index: 0,
+            siblings: 3,
+            parent: String::new(),
+            parent_id,
+            rune: (),
+        }
+    }
+
+    fn build_runes() -> HashMap<i64, RuneExtended<()>> {
+        HashMap::from([
+            (8005, rune(0, PRECISION)),
+            (8010, rune(0, PRECISION)),
+            (9111, rune(1, PRECISION)),
+            (9104, rune(2, PRECISION)),
+            (8299, rune(3, PRECISION)),
+            (8112, rune(0, DOMINATION)),
+            (8143, rune(1, DOMINATION)),
+            (8135, rune(3, DOMINATION)),
+            (8136, rune(3, DOMINATION)),
+        ])
+    }
+
+    fn builder<'a>(
+        runes: &'a HashMap<i64, RuneExtended<()>>,
+        rune_ids: &[i64],
+    ) -> RunePageBuilder<'a, ()> {
+        RunePageBuilder::new(runes)
+            .name("uggo")
+            .styles(PRECISION, DOMINATION)
+            .rune_ids(rune_ids)
+            .shard_ids(&[5008, 5008, 5002])
+    }
+
+    #[test]
+    fn valid_page_orders_perks() {
+        let runes = build_runes();
+        let page = builder(&runes, &[8135, 9104, 8005, 8143, 8299, 9111])
+            .build()
+            .unwrap();
+
+        assert_eq!(page.primary_style_id, PRECISION);
+        assert_eq!(page.sub_style_id, DOMINATION);
+        assert_eq!(
+            page.selected_perk_ids,
+            vec![8005, 9111, 9104, 8299, 8143, 8135, 5008, 5008, 5002]
+        );
+    }
+
+    #[test]
+    fn two_keystones_are_rejected() {
+        let runes = build_runes();
+        let result = builder(&runes, &[8005, 8010, 9111, 9104, 8143, 8135]).build();
+
+        assert_eq!(result, Err(RunePageError::KeystoneCount(2)));
+    }
+
+    #[test]
+    fn secondary_runes_need_different_slots() {
+        let runes = build_runes();
+        let result = builder(&runes, &[8005, 9111, 9104, 8299, 8135, 8136]).build();
+
+        assert_eq!(result, Err(RunePageError::InvalidSecondarySlots));
+    }
+
+    #[test]
+    fn missing_styles_and_unknown_runes_are_reported() {
+        let runes = build_runes();
+
+        assert_eq!(
+            builder(&runes, &[]).styles(0, DOMINATION).build(),
+            Err(RunePageError::MissingStyle)
+        );
+        assert_eq!(
+            builder(&runes, &[1234]).build(),
+            Err(RunePageError::UnknownRune(1234))
+        );
+        assert_eq!(
+            builder(&runes, &[8005, 9111, 9104, 8299, 8143, 8135])
+                .shard_ids(&[5008])
+                .build(),
+            Err(RunePageError::ShardCount(1))
+        );
+    }
+}
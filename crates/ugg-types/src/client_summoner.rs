@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct ClientSummoner {
     pub account_id: i64,
     pub display_name: String,
+    pub game_name: String,
+    pub tag_line: String,
     pub internal_name: String,
     pub name_change_flag: bool,
     pub percent_complete_for_next_level: i64,
@@ -18,8 +20,20 @@ pub struct ClientSummoner {
     pub xp_until_next_level: i64,
 }
 
+impl ClientSummoner {
+    /// The `name#tag` Riot ID, falling back to the legacy display name on older clients.
+    #[must_use]
+    pub fn riot_id(&self) -> String {
+        if self.game_name.is_empty() {
+            self.display_name.clone()
+        } else {
+            format!("{}#{}", self.game_name, self.tag_line)
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct RerollPoints {
     pub current_points: i64,
     pub max_rolls: i64,
@@ -27,3 +41,19 @@ pub struct RerollPoints {
     pub points_cost_to_roll: i64,
     pub points_to_reroll: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn riot_id_prefers_game_name() {
+        let summoner: ClientSummoner = serde_json::from_str(
+            r#"{"displayName": "", "gameName": "Faker", "tagLine": "KR1", "summonerLevel": 42}"#,
+        )
+        .unwrap();
+
+        assert_eq!(summoner.riot_id(), "Faker#KR1");
+        assert_eq!(summoner.summoner_level, 42);
+    }
+}
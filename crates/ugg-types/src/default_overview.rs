@@ -5,7 +5,7 @@ use serde::Serialize;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 use std::fmt;
 
-use crate::overview::handle_unknown;
+use crate::overview::{handle_unknown, winrate};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct OverviewData {
@@ -108,6 +108,13 @@ pub struct Items {
     pub item_ids: Vec<i64>,
 }
 
+impl Items {
+    #[must_use]
+    pub fn winrate(&self) -> f64 {
+        winrate(self.wins, self.matches)
+    }
+}
+
 impl<'de> Deserialize<'de> for Items {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -184,6 +191,13 @@ pub struct LateItem {
     pub id: i64,
 }
 
+impl LateItem {
+    #[must_use]
+    pub fn winrate(&self) -> f64 {
+        winrate(self.wins, self.matches)
+    }
+}
+
 impl<'de> Deserialize<'de> for LateItem {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -1,9 +1,20 @@
 pub mod arena_overview;
+pub mod build_progress;
+pub mod champion_search;
+pub mod client_champ_select;
+pub mod client_champions;
+pub mod client_chat;
+pub mod client_gameflow;
+pub mod client_mastery;
+pub mod client_ranked;
+pub mod client_ready_check;
 pub mod client_runepage;
 pub mod client_summoner;
 pub mod default_overview;
+pub mod live_client_data;
 pub mod mappings;
+pub mod markup;
 pub mod matchups;
 pub mod overview;
 pub mod rune;
-pub mod client_champ_select; // <--- DÒNG MỚI THÊM
+pub mod stat_shards;
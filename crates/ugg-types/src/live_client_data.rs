@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AllGameData {
+    pub active_player: ActivePlayer,
+    pub all_players: Vec<LivePlayer>,
+    pub game_data: LiveGameData,
+}
+
+impl AllGameData {
+    /// Finds the entry in `all_players` belonging to the active player.
+    #[must_use]
+    pub fn me(&self) -> Option<&LivePlayer> {
+        self.all_players.iter().find(|p| {
+            if self.active_player.riot_id.is_empty() {
+                p.summoner_name == self.active_player.summoner_name
+            } else {
+                p.riot_id == self.active_player.riot_id
+            }
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ActivePlayer {
+    pub abilities: ActivePlayerAbilities,
+    pub current_gold: f64,
+    pub level: i64,
+    pub riot_id: String,
+    pub summoner_name: String,
+}
+
+impl ActivePlayer {
+    #[must_use]
+    pub fn skill_points_spent(&self) -> i64 {
+        [
+            &self.abilities.q,
+            &self.abilities.w,
+            &self.abilities.e,
+            &self.abilities.r,
+        ]
+        .iter()
+        .map(|a| a.ability_level)
+        .sum()
+    }
+
+    #[must_use]
+    pub fn skill_points_available(&self) -> i64 {
+        (self.level - self.skill_points_spent()).max(0)
+    }
+
+    /// The ability that `ability_order` says should receive the next skill point.
+    #[must_use]
+    pub fn next_ability(&self, ability_order: &[char]) -> Option<char> {
+        usize::try_from(self.skill_points_spent())
+            .ok()
+            .and_then(|spent| ability_order.get(spent))
+            .copied()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivePlayerAbilities {
+    #[serde(rename = "Q")]
+    pub q: LiveAbility,
+    #[serde(rename = "W")]
+    pub w: LiveAbility,
+    #[serde(rename = "E")]
+    pub e: LiveAbility,
+    #[serde(rename = "R")]
+    pub r: LiveAbility,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveAbility {
+    pub ability_level: i64,
+    pub display_name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LivePlayer {
+    pub champion_name: String,
+    pub is_bot: bool,
+    pub items: Vec<LiveItem>,
+    pub level: i64,
+    pub position: String,
+    pub riot_id: String,
+    pub summoner_name: String,
+    pub team: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveItem {
+    pub consumable: bool,
+    pub count: i64,
+    pub display_name: String,
+    #[serde(rename = "itemID")]
+    pub item_id: i64,
+    pub slot: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveGameData {
+    pub game_mode: String,
+    pub game_time: f64,
+    pub map_number: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_active_player(level: i64, q: i64, w: i64, e: i64, r: i64) -> ActivePlayer {
+        let ability = |ability_level| LiveAbility {
+            ability_level,
+            display_name: String::new(),
+        };
+        ActivePlayer {
+            abilities: ActivePlayerAbilities {
+                q: ability(q),
+                w: ability(w),
+                e: ability(e),
+                r: ability(r),
+            },
+            level,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn next_ability_follows_order() {
+        let order = ['Q', 'E', 'W', 'Q', 'Q', 'R'];
+
+        assert_eq!(
+            build_active_player(1, 0, 0, 0, 0).next_ability(&order),
+            Some('Q')
+        );
+        assert_eq!(
+            build_active_player(3, 1, 0, 1, 0).next_ability(&order),
+            Some('W')
+        );
+        assert_eq!(
+            build_active_player(6, 3, 1, 1, 0).next_ability(&order),
+            Some('R')
+        );
+        assert_eq!(
+            build_active_player(7, 3, 1, 1, 1).next_ability(&order),
+            None
+        );
+    }
+
+    #[test]
+    fn skill_points_available() {
+        assert_eq!(
+            build_active_player(1, 0, 0, 0, 0).skill_points_available(),
+            1
+        );
+        assert_eq!(
+            build_active_player(3, 1, 1, 1, 0).skill_points_available(),
+            0
+        );
+        assert_eq!(
+            build_active_player(6, 2, 1, 1, 0).skill_points_available(),
+            2
+        );
+    }
+
+    #[test]
+    fn abilities_deserialize_from_live_data() {
+        let player: ActivePlayer = serde_json::from_str(
+            r#"{"abilities":{"Q":{"abilityLevel":2,"displayName":"Decisive Strike"},"W":{"abilityLevel":1}},"level":4}"#,
+        )
+        .unwrap();
+
+        assert_eq!(player.abilities.q.display_name, "Decisive Strike");
+        assert_eq!(player.skill_points_spent(), 3);
+        assert_eq!(player.skill_points_available(), 1);
+    }
+}
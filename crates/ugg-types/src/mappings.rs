@@ -78,6 +78,24 @@ impl Display for Rank {
 }
 
 impl Rank {
+    /// Maps a ranked tier reported by the client (e.g. `GOLD`) to the matching u.gg rank.
+    #[must_use]
+    pub fn from_client_tier(tier: &str) -> Option<Rank> {
+        match tier.to_uppercase().as_str() {
+            "IRON" => Some(Rank::Iron),
+            "BRONZE" => Some(Rank::Bronze),
+            "SILVER" => Some(Rank::Silver),
+            "GOLD" => Some(Rank::Gold),
+            "PLATINUM" => Some(Rank::Platinum),
+            "EMERALD" => Some(Rank::Emerald),
+            "DIAMOND" => Some(Rank::Diamond),
+            "MASTER" => Some(Rank::Master),
+            "GRANDMASTER" => Some(Rank::Grandmaster),
+            "CHALLENGER" => Some(Rank::Challenger),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn preferred_order() -> [Rank; 16] {
         // Prefer Platinum+, then overall, the plus ranks from lowest to highest,
@@ -103,7 +121,7 @@ impl Rank {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug, Default)]
 pub enum Region {
     #[serde(rename = "1")]
     NA1 = 1,
@@ -139,6 +157,7 @@ pub enum Region {
     JP1,
 
     #[serde(rename = "12")]
+    #[default]
     World,
 
     #[serde(rename = "13")]
@@ -211,12 +230,6 @@ impl Region {
     }
 }
 
-impl Default for Region {
-    fn default() -> Self {
-        Self::World
-    }
-}
-
 impl Display for Region {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let region_str = match self {
@@ -264,7 +277,7 @@ impl FromStr for Region {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug, Default)]
 pub enum Role {
     #[serde(rename = "1")]
     Jungle = 1,
@@ -286,6 +299,7 @@ pub enum Role {
     None,
 
     #[serde(rename = "7")]
+    #[default]
     Automatic,
 
     /// Only used for Nexus Blitz.
@@ -293,13 +307,20 @@ pub enum Role {
     Lane,
 }
 
-impl Default for Role {
-    fn default() -> Self {
-        Self::Automatic
+impl Role {
+    /// Maps the `assignedPosition` reported by the client during champ select.
+    #[must_use]
+    pub fn from_client_position(position: &str) -> Option<Role> {
+        match position.to_lowercase().as_str() {
+            "top" => Some(Role::Top),
+            "jungle" => Some(Role::Jungle),
+            "middle" | "mid" => Some(Role::Mid),
+            "bottom" | "adc" => Some(Role::ADCarry),
+            "utility" | "support" => Some(Role::Support),
+            _ => None,
+        }
     }
-}
 
-impl Role {
     #[must_use]
     pub const fn all() -> &'static [Role; 8] {
         &[
@@ -367,9 +388,10 @@ impl FromStr for Role {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Mode {
+    #[default]
     Normal,
     ARAM,
     OneForAll,
@@ -458,14 +480,9 @@ impl FromStr for Mode {
     }
 }
 
-impl Default for Mode {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum Build {
+    #[default]
     Recommended,
     OnHit,
     Crit,
@@ -475,12 +492,6 @@ pub enum Build {
     Tank,
 }
 
-impl Default for Build {
-    fn default() -> Self {
-        Self::Recommended
-    }
-}
-
 impl From<&str> for Build {
     fn from(kind_str: &str) -> Self {
         match kind_str.to_lowercase().as_str() {
@@ -572,4 +583,23 @@ mod tests {
         assert_eq!(get_role("Adc"), Role::ADCarry);
         assert_eq!(get_role("jungle"), Role::Jungle);
     }
+
+    #[test]
+    fn test_role_from_client_position() {
+        assert_eq!(Role::from_client_position("utility"), Some(Role::Support));
+        assert_eq!(Role::from_client_position("bottom"), Some(Role::ADCarry));
+        assert_eq!(Role::from_client_position("middle"), Some(Role::Mid));
+        assert_eq!(Role::from_client_position(""), None);
+    }
+
+    #[test]
+    fn test_rank_from_client_tier() {
+        assert_eq!(Rank::from_client_tier("EMERALD"), Some(Rank::Emerald));
+        assert_eq!(
+            Rank::from_client_tier("Grandmaster"),
+            Some(Rank::Grandmaster)
+        );
+        assert_eq!(Rank::from_client_tier("UNRANKED"), None);
+        assert_eq!(Rank::from_client_tier(""), None);
+    }
 }
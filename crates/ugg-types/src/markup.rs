@@ -0,0 +1,32 @@
+/// Strips the tooltip markup used in Data Dragon and `CommunityDragon` descriptions, turning
+/// line breaks into spaces.
+#[must_use]
+pub fn strip_markup(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in text.replace("<br>", " ").chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_tags_and_line_breaks() {
+        assert_eq!(
+            strip_markup(
+                "<mainText><stats>+40 Ability Power</stats><br>Deals <magicDamage>damage</magicDamage>.</mainText>"
+            ),
+            "+40 Ability Power Deals damage."
+        );
+        assert_eq!(strip_markup("No markup"), "No markup");
+    }
+}
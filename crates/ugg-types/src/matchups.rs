@@ -51,9 +51,36 @@ impl<'de> Deserialize<'de> for WrappedMatchupData {
 pub struct MatchupData {
     pub best_matchups: Vec<Matchup>,
     pub worst_matchups: Vec<Matchup>,
+    /// Every matchup with a meaningful sample size, sorted by win rate.
+    pub all_matchups: Vec<Matchup>,
     pub total_matches: i32,
 }
 
+impl MatchupData {
+    #[must_use]
+    pub fn winrate_against(&self, champion_id: i64) -> Option<f64> {
+        self.all_matchups
+            .iter()
+            .find(|m| m.champion_id == champion_id)
+            .map(|m| m.winrate)
+    }
+
+    /// Average win rate against whichever of `champion_ids` there's data for.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_winrate_against(&self, champion_ids: &[i64]) -> Option<f64> {
+        let winrates = champion_ids
+            .iter()
+            .filter_map(|id| self.winrate_against(*id))
+            .collect::<Vec<_>>();
+        if winrates.is_empty() {
+            None
+        } else {
+            Some(winrates.iter().sum::<f64>() / winrates.len() as f64)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Matchup {
     pub champion_id: i64,
@@ -118,6 +145,7 @@ impl<'de> Deserialize<'de> for MatchupData {
                     let matchup_data = MatchupData {
                         best_matchups,
                         worst_matchups,
+                        all_matchups,
                         total_matches,
                     };
                     Ok(matchup_data)
@@ -125,6 +153,7 @@ impl<'de> Deserialize<'de> for MatchupData {
                     Ok(MatchupData {
                         best_matchups: vec![],
                         worst_matchups: vec![],
+                        all_matchups,
                         total_matches: 0,
                     })
                 }
@@ -176,3 +205,41 @@ impl<'de> Deserialize<'de> for InnerData {
         deserializer.deserialize_seq(InnerSeqVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_matchup_data(matchups: &[(i64, f64)]) -> MatchupData {
+        MatchupData {
+            best_matchups: vec![],
+            worst_matchups: vec![],
+            all_matchups: matchups
+                .iter()
+                .map(|(champion_id, winrate)| Matchup {
+                    champion_id: *champion_id,
+                    wins: 0,
+                    matches: 0,
+                    winrate: *winrate,
+                })
+                .collect(),
+            total_matches: 0,
+        }
+    }
+
+    #[test]
+    fn winrate_against_known_champion() {
+        let data = build_matchup_data(&[(1, 0.6), (2, 0.4)]);
+
+        assert_eq!(data.winrate_against(2), Some(0.4));
+        assert_eq!(data.winrate_against(3), None);
+    }
+
+    #[test]
+    fn average_winrate_skips_unknown_champions() {
+        let data = build_matchup_data(&[(1, 0.6), (2, 0.4)]);
+
+        assert_eq!(data.average_winrate_against(&[1, 2, 3]), Some(0.5));
+        assert_eq!(data.average_winrate_against(&[3]), None);
+    }
+}
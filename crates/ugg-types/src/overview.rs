@@ -2,7 +2,7 @@
 // structure of the champ overview stats data.
 
 use crate::arena_overview::ArenaOverviewData;
-use crate::default_overview::{Abilities, OverviewData};
+use crate::default_overview::{Abilities, Items, LateItem, OverviewData};
 use crate::mappings;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 use serde::{Deserialize as DeserializeDerive, Serialize};
@@ -18,6 +18,16 @@ pub fn handle_unknown<T: Default, E>(result: Result<Option<T>, E>) -> T {
     result.ok().flatten().unwrap_or_default()
 }
 
+/// The share of `matches` that were won, or 0 without any matches.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn winrate(wins: i64, matches: i64) -> f64 {
+    if matches <= 0 {
+        return 0f64;
+    }
+    (wins as f64) / (matches as f64)
+}
+
 #[derive(Debug, Clone, Serialize, DeserializeDerive)]
 #[serde(untagged)]
 pub enum Overview {
@@ -34,6 +44,14 @@ impl Overview {
         }
     }
 
+    #[must_use]
+    pub fn wins(&self) -> i64 {
+        match self {
+            Overview::Arena(a) => a.wins,
+            Overview::Default(d) => d.wins,
+        }
+    }
+
     #[must_use]
     pub fn abilities(&self) -> Abilities {
         match self {
@@ -42,6 +60,22 @@ impl Overview {
         }
     }
 
+    #[must_use]
+    pub fn core_items(&self) -> &Items {
+        match self {
+            Overview::Arena(a) => &a.core_items,
+            Overview::Default(d) => &d.core_items,
+        }
+    }
+
+    #[must_use]
+    pub fn late_item_options(&self) -> [&[LateItem]; 3] {
+        match self {
+            Overview::Arena(a) => [&a.item_4_options, &a.item_5_options, &a.item_6_options],
+            Overview::Default(d) => [&d.item_4_options, &d.item_5_options, &d.item_6_options],
+        }
+    }
+
     #[must_use]
     pub fn low_sample_size(&self) -> bool {
         match self {
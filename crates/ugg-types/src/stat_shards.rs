@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::markup::strip_markup;
+
+const STAT_MOD_SLOT: &str = "kStatMod";
+const DEFAULT_SLOT_LABELS: [&str; 3] = ["Offense", "Flex", "Defense"];
+
+/// Shards known at the time of writing, used when `CommunityDragon` can't be reached: ID, name
+/// and description.
+const FALLBACK_SHARDS: [(i64, &str, &str); 9] = [
+    (5001, "Health Scaling", "+10-180 Health"),
+    (5002, "Armor", "+6 Armor"),
+    (5003, "Magic Resist", "+8 Magic Resist"),
+    (5005, "Attack Speed", "+10% Attack Speed"),
+    (5007, "Ability Haste", "+8 Ability Haste"),
+    (5008, "Adaptive Force", "+9 Adaptive Force"),
+    (5010, "Move Speed", "+2% Move Speed"),
+    (5011, "Health", "+65 Health"),
+    (
+        5013,
+        "Tenacity and Slow Resist",
+        "+10% Tenacity and Slow Resist",
+    ),
+];
+const FALLBACK_SLOTS: [[i64; 3]; 3] = [[5008, 5005, 5007], [5008, 5010, 5001], [5011, 5013, 5001]];
+
+/// An entry of `perks.json` from `CommunityDragon`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CDragonPerk {
+    pub id: i64,
+    pub name: String,
+    pub short_desc: String,
+    pub icon_path: String,
+}
+
+impl CDragonPerk {
+    /// The short description without the client's tooltip markup, e.g. `+9 Adaptive Force`.
+    #[must_use]
+    pub fn description(&self) -> String {
+        let text = strip_markup(&self.short_desc);
+        let text = text.trim();
+        if text.is_empty() {
+            self.name.clone()
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// `perkstyles.json` from `CommunityDragon`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CDragonPerkStyles {
+    pub styles: Vec<CDragonPerkStyle>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CDragonPerkStyle {
+    pub id: i64,
+    pub name: String,
+    pub slots: Vec<CDragonPerkSlot>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CDragonPerkSlot {
+    #[serde(rename = "type")]
+    pub slot_type: String,
+    pub slot_label: String,
+    pub perks: Vec<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct StatShardSlot {
+    pub label: String,
+    pub shard_ids: Vec<i64>,
+}
+
+/// The stat shard rows of a rune page, and the shards that can go in them.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct StatShards {
+    pub slots: Vec<StatShardSlot>,
+    pub shards: HashMap<i64, CDragonPerk>,
+}
+
+impl StatShards {
+    /// Picks the stat mod slots out of the perk styles (every style shares them) and the
+    /// perks that fill them.
+    #[must_use]
+    pub fn new(styles: &CDragonPerkStyles, perks: Vec<CDragonPerk>) -> Self {
+        let slots = styles
+            .styles
+            .first()
+            .map(|style| {
+                style
+                    .slots
+                    .iter()
+                    .filter(|s| s.slot_type == STAT_MOD_SLOT)
+                    .enumerate()
+                    .map(|(i, s)| StatShardSlot {
+                        label: if s.slot_label.is_empty() {
+                            DEFAULT_SLOT_LABELS.get(i).unwrap_or(&"").to_string()
+                        } else {
+                            s.slot_label.clone()
+                        },
+                        shard_ids: s.perks.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let shards = perks
+            .into_iter()
+            .filter(|p| slots.iter().any(|s| s.shard_ids.contains(&p.id)))
+            .map(|p| (p.id, p))
+            .collect();
+
+        Self { slots, shards }
+    }
+
+    /// The shard rows known at the time of writing, for when `CommunityDragon` can't be reached.
+    #[must_use]
+    pub fn fallback() -> Self {
+        Self {
+            slots: DEFAULT_SLOT_LABELS
+                .iter()
+                .zip(FALLBACK_SLOTS)
+                .map(|(label, shard_ids)| StatShardSlot {
+                    label: (*label).to_string(),
+                    shard_ids: shard_ids.to_vec(),
+                })
+                .collect(),
+            shards: FALLBACK_SHARDS
+                .iter()
+                .map(|(id, name, description)| {
+                    let perk = CDragonPerk {
+                        id: *id,
+                        name: (*name).to_string(),
+                        short_desc: (*description).to_string(),
+                        ..CDragonPerk::default()
+                    };
+                    (*id, perk)
+                })
+                .collect(),
+        }
+    }
+
+    /// The label of the `index`th shard row.
+    #[must_use]
+    pub fn slot_label(&self, index: usize) -> &str {
+        match self.slots.get(index) {
+            Some(slot) => &slot.label,
+            None => DEFAULT_SLOT_LABELS.get(index).copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_shards() -> StatShards {
+        let styles: CDragonPerkStyles = serde_json::from_str(
+            r#"{"styles": [{"id": 8000, "name": "Precision", "slots": [
+                {"type": "kKeyStone", "slotLabel": "", "perks": [8005]},
+                {"type": "kStatMod", "slotLabel": "Offense", "perks": [5008, 5005]},
+                {"type": "kStatMod", "slotLabel": "Flex", "perks": [5008, 5010]},
+                {"type": "kStatMod", "slotLabel": "", "perks": [5011, 5013]}
+            ]}]}"#,
+        )
+        .unwrap();
+        let perks: Vec<CDragonPerk> = serde_json::from_str(
+            r#"[
+                {"id": 8005, "name": "Press the Attack", "shortDesc": "..."},
+                {"id": 5008, "name": "Adaptive Force", "shortDesc": "+9 <lol-uikit-tooltipped-keyword key='LinkTooltip_Description_Adaptive'>Adaptive Force</lol-uikit-tooltipped-keyword>"},
+                {"id": 5011, "name": "Health", "shortDesc": ""}
+            ]"#,
+        )
+        .unwrap();
+
+        StatShards::new(&styles, perks)
+    }
+
+    #[test]
+    fn stat_mod_slots_are_picked() {
+        let shards = build_shards();
+
+        assert_eq!(shards.slots.len(), 3);
+        assert_eq!(shards.slot_label(0), "Offense");
+        assert_eq!(shards.slot_label(2), "Defense");
+        assert!(!shards.shards.contains_key(&8005));
+    }
+
+    #[test]
+    fn description_strips_markup() {
+        let shards = build_shards();
+
+        assert_eq!(shards.shards[&5008].description(), "+9 Adaptive Force");
+        assert_eq!(shards.shards[&5011].description(), "Health");
+    }
+
+    #[test]
+    fn fallback_covers_every_slot() {
+        let shards = StatShards::fallback();
+
+        assert_eq!(shards.slot_label(1), "Flex");
+        assert!(
+            shards
+                .slots
+                .iter()
+                .flat_map(|s| &s.shard_ids)
+                .all(|id| shards.shards.contains_key(id))
+        );
+        assert_eq!(shards.shards[&5011].description(), "+65 Health");
+    }
+}
@@ -1,38 +1,102 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Style, Stylize},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
 use ugg_types::overview::Overview;
 
-fn format_ability_level_order(ability_order: &[char], ability: char) -> String {
-    ability_order
-        .iter()
-        .copied()
-        .map(|c| {
-            if c == ability {
-                "●".to_string()
-            } else {
-                " ".to_string()
-            }
-        })
-        .collect::<Vec<String>>()
-        .join(" ")
+use crate::theme;
+
+/// Draws one row of the chart, with the level at `next_level` (if any) highlighted.
+fn format_ability_level_order(
+    ability_order: &[char],
+    ability: char,
+    next_level: Option<usize>,
+) -> Line<'static> {
+    let mut spans = Vec::with_capacity(ability_order.len() * 2);
+    for (level, c) in ability_order.iter().copied().enumerate() {
+        if level > 0 {
+            spans.push(Span::raw(" "));
+        }
+        let dot = if c == ability { "●" } else { " " };
+        if next_level == Some(level) {
+            spans.push(Span::styled(
+                dot,
+                Style::default().add_modifier(Modifier::REVERSED),
+            ));
+        } else {
+            spans.push(Span::raw(dot));
+        }
+    }
+    Line::from(spans)
 }
 
 const ABILITY_LEFT_OFFSET: u16 = 4;
 const ABILITY_WIDTH: u16 = (5 /* Q */ + 5 /* W */ + 5 /* E */ + 3 /* R */) * 2 /* spaces */;
+const ABILITY_KEYS: [char; 4] = ['Q', 'W', 'E', 'R'];
+
+/// When `ability` is maxed according to `max_order` (e.g. `QEW`), like `max 1st`.
+pub fn max_priority(max_order: &str, ability: char) -> Option<String> {
+    let position = max_order.chars().position(|c| c == ability)?;
+    let ordinal = match position {
+        0 => "1st",
+        1 => "2nd",
+        2 => "3rd",
+        _ => return None,
+    };
+    Some(format!("max {ordinal}"))
+}
+
+/// The spell name next to each row, with its max priority, e.g. `Orb of Deception (max 1st)`.
+fn format_spell_name(name: &str, max_order: &str, ability: char) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        name.to_string(),
+        Style::default().fg(theme::current().text),
+    )];
+    if let Some(priority) = max_priority(max_order, ability) {
+        spans.push(Span::styled(
+            format!(" ({priority})"),
+            Style::default().fg(theme::current().muted),
+        ));
+    }
+    Line::from(spans)
+}
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(" Ability Order ")
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
-pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
+/// Draws the chart into `bounds`. `spell_names` are the champion's Q/W/E/R names, shown to the
+/// right of the chart if there's room.
+pub fn make(
+    bounds: Rect,
+    overview: &Overview,
+    next_level: Option<usize>,
+    spell_names: &[String],
+) -> Vec<(impl Widget, Rect)> {
     let abilities = overview.abilities();
+    let names = ABILITY_KEYS
+        .iter()
+        .zip(spell_names)
+        .zip(0u16..)
+        .map(|((key, name), row)| {
+            (
+                Paragraph::new(format_spell_name(name, &abilities.ability_max_order, *key)),
+                Rect::new(
+                    bounds.left() + 3 + ABILITY_WIDTH + 1,
+                    bounds.top() + row,
+                    bounds.width,
+                    1,
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+
     vec![
         // Draw Q |
         (
@@ -41,8 +105,12 @@ pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
         ),
         // Draw Q abilities
         (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'Q'))
-                .style(Style::default().fg(Color::Cyan).bold()),
+            Paragraph::new(format_ability_level_order(
+                &abilities.ability_order,
+                'Q',
+                next_level,
+            ))
+            .style(Style::default().fg(theme::current().abilities[0]).bold()),
             Rect::new(bounds.left() + 3, bounds.top(), ABILITY_WIDTH, 1),
         ),
         // Draw W |
@@ -52,8 +120,12 @@ pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
         ),
         // Draw W abilities
         (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'W'))
-                .style(Style::default().fg(Color::Yellow).bold()),
+            Paragraph::new(format_ability_level_order(
+                &abilities.ability_order,
+                'W',
+                next_level,
+            ))
+            .style(Style::default().fg(theme::current().abilities[1]).bold()),
             Rect::new(bounds.left() + 3, bounds.top() + 1, ABILITY_WIDTH, 1),
         ),
         // Draw E |
@@ -63,8 +135,12 @@ pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
         ),
         // Draw E abilities
         (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'E'))
-                .style(Style::default().fg(Color::Green).bold()),
+            Paragraph::new(format_ability_level_order(
+                &abilities.ability_order,
+                'E',
+                next_level,
+            ))
+            .style(Style::default().fg(theme::current().abilities[2]).bold()),
             Rect::new(bounds.left() + 3, bounds.top() + 2, ABILITY_WIDTH, 1),
         ),
         // Draw R |
@@ -74,9 +150,32 @@ pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
         ),
         // Draw R abilities
         (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'R'))
-                .style(Style::default().fg(Color::Red).bold()),
+            Paragraph::new(format_ability_level_order(
+                &abilities.ability_order,
+                'R',
+                next_level,
+            ))
+            .style(Style::default().fg(theme::current().abilities[3]).bold()),
             Rect::new(bounds.left() + 3, bounds.top() + 3, ABILITY_WIDTH, 1),
         ),
     ]
+    .into_iter()
+    .chain(names)
+    // Rows are laid out at fixed offsets, so clip them on narrow terminals.
+    .map(|(w, r)| (w, r.intersection(bounds)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_priority_follows_the_max_order() {
+        assert_eq!(max_priority("QEW", 'Q').as_deref(), Some("max 1st"));
+        assert_eq!(max_priority("QEW", 'E').as_deref(), Some("max 2nd"));
+        assert_eq!(max_priority("QEW", 'W').as_deref(), Some("max 3rd"));
+        assert_eq!(max_priority("QEW", 'R'), None);
+        assert_eq!(max_priority("", 'Q'), None);
+    }
 }
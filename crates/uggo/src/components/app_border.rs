@@ -1,19 +1,29 @@
+use std::time::Instant;
+
 use ratatui::{
     style::{Style, Stylize},
     text::Line,
     widgets::{Block, Borders, Widget},
 };
 
+use crate::components::summoner;
 use crate::context::AppContext;
+use crate::theme;
+
+fn rank_label(ctx: &AppContext) -> String {
+    ctx.rank
+        .map_or_else(|| "Auto".to_string(), |r| r.to_string())
+}
 
 #[allow(clippy::cast_precision_loss)]
 #[cfg(debug_assertions)]
 fn make_bottom_right_title<'a>(ctx: &'a AppContext) -> Line<'a> {
     Line::from(format!(
-        " [Mode: {}] [Patch: {}] [Region: {}] [Render: {:.2}ms] ",
+        " [Mode: {}] [Patch: {}] [Region: {}] [Rank: {}] [Render: {:.2}ms] ",
         ctx.mode,
         ctx.version,
         ctx.region,
+        rank_label(ctx),
         ctx.last_render_duration
             .map_or(0.0, |d| d.as_micros() as f64 / 1000.0)
     ))
@@ -24,18 +34,40 @@ fn make_bottom_right_title<'a>(ctx: &'a AppContext) -> Line<'a> {
 #[cfg(not(debug_assertions))]
 fn make_bottom_right_title<'a>(ctx: &'a AppContext) -> Line<'a> {
     Line::from(format!(
-        " [Mode: {}] [Patch: {}] [Region: {}] ",
-        ctx.mode, ctx.version, ctx.region
+        " [Mode: {}] [Patch: {}] [Region: {}] [Rank: {}] ",
+        ctx.mode,
+        ctx.version,
+        ctx.region,
+        rank_label(ctx)
     ))
     .right_aligned()
 }
 
+fn make_client_title<'a>(ctx: &'a AppContext) -> Line<'a> {
+    if ctx.client_api.is_none() {
+        return Line::from(" [Client: Offline] ").centered();
+    }
+
+    let auto_accept = match ctx.ready_check.remaining(Instant::now()) {
+        Some(remaining) => format!("Accepting in {}s", remaining.as_secs()),
+        None if ctx.ready_check.enabled => "On".to_string(),
+        None => "Off".to_string(),
+    };
+    Line::from(format!(
+        " [Client: {}] [Auto-accept: {auto_accept}] ",
+        ctx.gameflow.phase()
+    ))
+    .centered()
+}
+
 pub fn make<'a>(ctx: &'a AppContext) -> impl Widget + 'a {
     Block::default()
+        .title_top(summoner::make_title(ctx))
         .title_top(Line::from(format!(" uggo v{} ", env!("CARGO_PKG_VERSION"))).centered())
         .title_bottom(Line::from(" [Help: ?] ").left_aligned())
+        .title_bottom(make_client_title(ctx))
         .title_bottom(make_bottom_right_title(ctx))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
-        .magenta()
+        .fg(theme::current().border)
 }
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use ddragon::models::cdragon::AugmentRarity;
+use ratatui::{
+    Frame,
+    crossterm::event::{Event, KeyEvent},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+use tui_input::backend::crossterm::EventHandler;
+use ugg_types::{
+    arena_overview::{ArenaOverviewData, Augment},
+    markup::strip_markup,
+    overview::Overview,
+};
+
+use crate::context::{AppContext, State};
+use crate::theme;
+use crate::util;
+
+const RARITIES: [(AugmentRarity, &str); 3] = [
+    (AugmentRarity::Silver, "Silver"),
+    (AugmentRarity::Gold, "Gold"),
+    (AugmentRarity::Prismatic, "Prismatic"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AugmentSort {
+    #[default]
+    WinRate,
+    PickRate,
+}
+
+impl AugmentSort {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::WinRate => "Win Rate",
+            Self::PickRate => "Pick Rate",
+        }
+    }
+}
+
+type AugmentEntry<'a> = (&'a Augment, &'a ddragon::models::Augment);
+
+/// Strips markup from a `CommunityDragon` augment description and fills in `@Value@`
+/// placeholders from its data values.
+fn describe(augment: &ddragon::models::Augment) -> String {
+    strip_markup(&augment.desc)
+        .split('@')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 0 {
+                return part.to_string();
+            }
+            let (name, scale) = part.split_once('*').unwrap_or((part, "1"));
+            augment
+                .data_values
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map_or_else(
+                    || format!("@{part}@"),
+                    |(_, v)| util::format_number(v * scale.parse::<f64>().unwrap_or(1.0)),
+                )
+        })
+        .collect()
+}
+
+fn augment_columns<'a>(
+    overview: &'a ArenaOverviewData,
+    game_augments: &'a HashMap<i64, ddragon::models::Augment>,
+    filter: &str,
+    sort: AugmentSort,
+) -> [Vec<AugmentEntry<'a>>; 3] {
+    let filter = filter.to_lowercase();
+    RARITIES.map(|(rarity, _)| {
+        let mut entries = overview
+            .augments
+            .iter()
+            .filter_map(|a| game_augments.get(&a.id).map(|g| (a, g)))
+            .filter(|(_, g)| g.rarity == rarity && g.name.to_lowercase().contains(&filter))
+            .collect::<Vec<_>>();
+        match sort {
+            AugmentSort::WinRate => {
+                entries.sort_by(|(a, _), (b, _)| b.winrate().total_cmp(&a.winrate()));
+            }
+            AugmentSort::PickRate => entries.sort_by_key(|(a, _)| -a.games()),
+        }
+        entries
+    })
+}
+
+fn make_block<'a>(title: String) -> Block<'a> {
+    Block::default()
+        .fg(theme::current().text)
+        .title(title)
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL)
+}
+
+pub fn render(frame: &mut Frame, ctx: &AppContext, area: Rect) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // filter / sort
+            Constraint::Min(0),    // rarity columns
+            Constraint::Length(6), // selected augment
+        ])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            ctx.augment_filter
+                .value()
+                .to_string()
+                .fg(theme::current().selected),
+            format!("  (sorted by {}, Tab to change)", ctx.augment_sort.label())
+                .fg(theme::current().muted),
+        ]))
+        .block(make_block(" Augment Explorer ".to_string())),
+        layout[0],
+    );
+
+    let Some(Overview::Arena(overview)) = &ctx.selected_champ_overview else {
+        frame.render_widget(
+            Paragraph::new(" Select an Arena champion to explore its augments.")
+                .fg(theme::current().warning),
+            layout[1],
+        );
+        return;
+    };
+
+    let columns_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(layout[1]);
+
+    let columns = augment_columns(
+        overview,
+        &ctx.api.arena_augments,
+        ctx.augment_filter.value(),
+        ctx.augment_sort,
+    );
+
+    for (idx, ((entries, (_, name)), rect)) in columns
+        .iter()
+        .zip(RARITIES.iter())
+        .zip(columns_layout.iter())
+        .enumerate()
+    {
+        let list = List::new(
+            entries
+                .iter()
+                .map(|(a, g)| {
+                    ListItem::new(format!(
+                        "{} {:.1}% WR, {:.1}% PR",
+                        g.name,
+                        a.winrate() * 100.0,
+                        a.pick_rate(overview.matches) * 100.0
+                    ))
+                })
+                .collect::<Vec<_>>(),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::current().selected)
+                .add_modifier(Modifier::ITALIC),
+        )
+        .highlight_symbol("> ")
+        .block(make_block(format!(" {name} ({}) ", entries.len())));
+
+        let mut state = ListState::default()
+            .with_selected((idx == ctx.augment_column).then_some(ctx.augment_pos));
+        frame.render_stateful_widget(list, *rect, &mut state);
+    }
+
+    if let Some((_, augment)) = columns
+        .get(ctx.augment_column)
+        .and_then(|c| c.get(ctx.augment_pos))
+    {
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(describe(augment)),
+                Line::from(augment.icon_large.clone()).fg(theme::current().muted),
+            ])
+            .wrap(Wrap { trim: true })
+            .block(make_block(format!(" {} ", augment.name))),
+            layout[2],
+        );
+    }
+}
+
+impl AppContext<'_> {
+    fn augment_column_len(&self) -> usize {
+        match &self.selected_champ_overview {
+            Some(Overview::Arena(overview)) => augment_columns(
+                overview,
+                &self.api.arena_augments,
+                self.augment_filter.value(),
+                self.augment_sort,
+            )
+            .get(self.augment_column)
+            .map_or(0, Vec::len),
+            _ => 0,
+        }
+    }
+
+    pub fn open_augment_explorer(&mut self) {
+        self.state = State::AugmentExplorer;
+        self.augment_pos = 0;
+    }
+
+    pub fn next_augment(&mut self) {
+        if self.augment_pos + 1 < self.augment_column_len() {
+            self.augment_pos += 1;
+        }
+    }
+
+    pub fn prev_augment(&mut self) {
+        self.augment_pos = self.augment_pos.saturating_sub(1);
+    }
+
+    pub fn next_augment_column(&mut self) {
+        self.augment_column = (self.augment_column + 1) % RARITIES.len();
+        self.augment_pos = 0;
+    }
+
+    pub fn prev_augment_column(&mut self) {
+        self.augment_column = (self.augment_column + RARITIES.len() - 1) % RARITIES.len();
+        self.augment_pos = 0;
+    }
+
+    pub fn toggle_augment_sort(&mut self) {
+        self.augment_sort = match self.augment_sort {
+            AugmentSort::WinRate => AugmentSort::PickRate,
+            AugmentSort::PickRate => AugmentSort::WinRate,
+        };
+        self.augment_pos = 0;
+    }
+
+    pub fn on_augment_filter_keypress(&mut self, key: KeyEvent) {
+        self.augment_filter.handle_event(&Event::Key(key));
+        self.augment_pos = 0;
+    }
+}
@@ -7,9 +7,11 @@ use ratatui::{
 };
 use ugg_types::arena_overview::{ArenaOverviewData, Augment};
 
+use crate::theme;
+
 fn make_augment_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
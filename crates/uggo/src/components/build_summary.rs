@@ -0,0 +1,121 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+use ugg_types::overview::Overview;
+
+use crate::context::{AppContext, State};
+use crate::theme;
+use crate::util;
+
+/// Formats the selected build as a single chat line, e.g.
+/// `Ahri: Electrocute (Domination + Sorcery) | Flash + Ignite | Luden's, ... | Max Q > W > E`.
+fn format_summary(ctx: &AppContext) -> Option<String> {
+    let champ = ctx.selected_champ.as_ref()?;
+    let overview = ctx.selected_champ_overview.as_ref()?;
+    let mut parts = Vec::new();
+
+    if let Overview::Default(data) = overview {
+        let groups = util::group_runes(&data.runes.rune_ids, &ctx.api.runes);
+        let primary = groups.iter().position(|(_, runes)| {
+            runes
+                .iter()
+                .any(|(_, rune)| rune.parent_id == data.runes.primary_style_id)
+        });
+        if let Some(primary) = primary
+            && let Some((_, keystone)) = groups[primary].1.iter().find(|(_, rune)| rune.slot == 0)
+        {
+            let mut trees = groups
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            // The primary tree goes first.
+            trees[..=primary].rotate_right(1);
+            parts.push(format!("{} ({})", keystone.rune.name, trees.join(" + ")));
+        }
+
+        let spells = data
+            .summoner_spells
+            .spell_ids
+            .iter()
+            .filter_map(|id| ctx.api.summoner_spells.get(id).cloned())
+            .collect::<Vec<_>>();
+        if !spells.is_empty() {
+            parts.push(spells.join(" + "));
+        }
+    }
+
+    let core_items = overview
+        .core_items()
+        .item_ids
+        .iter()
+        .filter_map(|id| ctx.api.items.get(&id.to_string()).map(|i| i.name.clone()))
+        .collect::<Vec<_>>();
+    if !core_items.is_empty() {
+        parts.push(core_items.join(", "));
+    }
+
+    let max_order = overview
+        .abilities()
+        .ability_max_order
+        .chars()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    if !max_order.is_empty() {
+        parts.push(format!("Max {}", max_order.join(" > ")));
+    }
+
+    Some(format!("{}: {}", champ.name, parts.join(" | ")))
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make_preview<'a>(message: &str) -> (impl Widget + 'a, Rect) {
+    let width = (message.len() as u16 / 2).clamp(30, 70);
+    (
+        Paragraph::new(vec![
+            Line::from(message.to_string()),
+            Line::default(),
+            Line::from("Enter to send, Esc to cancel").fg(theme::current().muted),
+        ])
+        .wrap(Wrap { trim: true })
+        .style(Style::default().fg(theme::current().text))
+        .block(
+            Block::default()
+                .title(" Send to Champ Select Chat ")
+                .title_style(Style::default().bold())
+                .borders(Borders::ALL),
+        ),
+        Rect::new(0, 0, width + 2, 7),
+    )
+}
+
+impl AppContext<'_> {
+    pub fn preview_build_summary(&mut self) {
+        self.chat_preview = format_summary(self);
+        if self.chat_preview.is_some() {
+            self.state = State::ChatPreview;
+        }
+    }
+
+    pub fn send_build_summary(&mut self) {
+        if let Some(message) = self.chat_preview.take() {
+            match self
+                .client_api
+                .as_ref()
+                .map(|api| api.send_champ_select_message(&message))
+            {
+                Some(Ok(())) => log::info!("Sent build summary to champ select"),
+                Some(Err(e)) => log::warn!("Could not send build summary: {e}"),
+                None => log::warn!("Could not send build summary, the client isn't running"),
+            }
+        }
+        self.return_to_initial(false);
+    }
+
+    pub fn cancel_build_summary(&mut self) {
+        self.chat_preview = None;
+        self.return_to_initial(false);
+    }
+}
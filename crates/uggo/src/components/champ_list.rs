@@ -1,23 +1,45 @@
 use ratatui::{
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
+    text::Line,
     widgets::{Block, Borders, List, ListState},
 };
 
 use crate::context::{AppContext, State};
+use crate::theme;
+
+/// The `#tag` and `@role` filters typed in the search box, shown under the list.
+fn filter_chips(input: &str) -> String {
+    let chips = input
+        .split_whitespace()
+        .filter(|token| token.len() > 1 && (token.starts_with('#') || token.starts_with('@')))
+        .collect::<Vec<_>>();
+    if chips.is_empty() {
+        String::new()
+    } else {
+        format!(" {} ", chips.join(" "))
+    }
+}
 
 pub fn make<'a>(ctx: &'a AppContext) -> (List<'a>, ListState) {
     (
         List::new(ctx.champ_list.clone())
             .block(
                 Block::default()
-                    .title(" Champions ")
-                    .style(Style::default().fg(Color::White).bold())
+                    .title(if ctx.owned_only && ctx.available_champs.is_some() {
+                        " Owned Champions "
+                    } else {
+                        " Champions "
+                    })
+                    .title_bottom(
+                        Line::from(filter_chips(ctx.input.value())).fg(theme::current().info),
+                    )
+                    .style(Style::default().fg(theme::current().text).bold())
                     .borders(Borders::ALL),
             )
-            .style(Style::default().fg(Color::White).not_bold())
+            .style(Style::default().fg(theme::current().text).not_bold())
             .highlight_style(
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme::current().selected)
                     .add_modifier(Modifier::ITALIC),
             )
             .highlight_symbol("> "),
@@ -54,6 +76,19 @@ impl AppContext<'_> {
         }
     }
 
+    pub fn toggle_owned_only(&mut self) {
+        self.owned_only = !self.owned_only;
+        if self.available_champs.is_none() {
+            self.refresh_available_champs();
+        } else {
+            self.update_champ_list();
+        }
+        self.champ_scroll_pos = self.champ_scroll_pos.map(|_| 0);
+        if self.champ_list.is_empty() {
+            self.champ_scroll_pos = None;
+        }
+    }
+
     pub fn go_to_search(&mut self) {
         self.state = State::TextInput;
         self.update_champ_list();
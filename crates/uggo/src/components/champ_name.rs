@@ -1,11 +1,12 @@
 use ddragon::models::champions::ChampionShort;
 use ratatui::{
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     widgets::{Paragraph, Widget},
 };
 use ugg_types::overview::Overview;
 
 use crate::context::AppContext;
+use crate::theme;
 
 pub fn make<'a>(
     ctx: &'a AppContext,
@@ -20,7 +21,7 @@ pub fn make<'a>(
                 ctx.selected_champ_role.unwrap_or(ctx.role),
                 ctx.build
             ),
-            Color::Yellow,
+            theme::current().warning,
         )
     } else {
         (
@@ -29,7 +30,7 @@ pub fn make<'a>(
                 ctx.selected_champ_role.unwrap_or(ctx.role),
                 ctx.build
             ),
-            Color::Green,
+            theme::current().good,
         )
     };
 
@@ -5,11 +5,20 @@ use ratatui::{
     style::{Style, Stylize},
     widgets::{Block, Borders, List, ListItem, Widget},
 };
-use ugg_types::arena_overview::{ArenaOverviewData, ChampionSynergy};
+use ugg_types::{
+    arena_overview::{ArenaOverviewData, ChampionSynergy},
+    client_champ_select::ChampSelectSession,
+    mappings::Mode,
+    overview::Overview,
+};
+use uggo_ugg_api::with_rank_fallback;
+
+use crate::context::AppContext;
+use crate::theme;
 
 fn make_synergy_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
@@ -19,18 +28,36 @@ pub fn make_placeholder() -> impl Widget {
     make_synergy_block("Champ Synergies")
 }
 
+fn make_synergy_item<'a>(synergy: &ChampionSynergy, champ: &ChampionShort) -> ListItem<'a> {
+    ListItem::new(format!(
+        "{} {:.1}% ({})",
+        champ.name,
+        synergy.top_four_rate() * 100.0,
+        synergy.games()
+    ))
+}
+
 fn make_list_from_champ_synergies<'a>(
     name: &str,
     synergies: &[ChampionSynergy],
     champs: &HashMap<String, ChampionShort>,
+    partner: Option<i64>,
 ) -> List<'a> {
+    // Keep the partner visible even when they're outside of the top entries.
+    let partner_synergy = partner.and_then(|id| synergies.iter().find(|s| s.id == id));
     List::new(
-        synergies
-            .iter()
-            .filter_map(|i| {
-                champs
-                    .get(&i.id.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
+        partner_synergy
+            .into_iter()
+            .chain(synergies.iter().filter(|s| Some(s.id) != partner))
+            .filter_map(|s| {
+                champs.get(&s.id.to_string()).map(|champ| {
+                    if Some(s.id) == partner {
+                        make_synergy_item(s, champ)
+                            .style(Style::default().fg(theme::current().warning).bold())
+                    } else {
+                        make_synergy_item(s, champ)
+                    }
+                })
             })
             .take(10)
             .collect::<Vec<_>>(),
@@ -38,6 +65,72 @@ fn make_list_from_champ_synergies<'a>(
     .block(make_synergy_block(name))
 }
 
-pub fn make(overview: &ArenaOverviewData, champs: &HashMap<String, ChampionShort>) -> impl Widget {
-    make_list_from_champ_synergies("Champ Synergies", &overview.champion_synergies, champs)
+pub fn make(
+    overview: &ArenaOverviewData,
+    champs: &HashMap<String, ChampionShort>,
+    partner: Option<i64>,
+) -> impl Widget {
+    make_list_from_champ_synergies(
+        "Champ Synergies",
+        &overview.champion_synergies,
+        champs,
+        partner,
+    )
+}
+
+/// Champions that pair best with our partner's pick, limited to the ones we can play.
+pub fn make_partner_candidates<'a>(
+    partner: &ChampionShort,
+    synergies: &[ChampionSynergy],
+    champs: &HashMap<String, ChampionShort>,
+    available: Option<&HashMap<String, bool>>,
+) -> List<'a> {
+    let playable = synergies
+        .iter()
+        .filter(|s| {
+            champs
+                .get(&s.id.to_string())
+                .is_some_and(|c| available.is_none_or(|a| a.contains_key(&c.key)))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    make_list_from_champ_synergies(
+        &format!("Best With {}", partner.name),
+        &playable,
+        champs,
+        None,
+    )
+}
+
+impl AppContext<'_> {
+    /// Tracks our Arena duo partner and fetches which champions pair best with their pick.
+    pub fn update_arena_partner(&mut self, session: &ChampSelectSession) {
+        let partner = session
+            .teammate_champion_id()
+            .filter(|_| self.mode == Mode::Arena);
+        if partner == self.arena_partner {
+            return;
+        }
+        self.arena_partner = partner;
+        self.partner_synergies.clear();
+
+        let Some(partner_champ) = partner.and_then(|id| self.champ_by_key.get(&id.to_string()))
+        else {
+            return;
+        };
+        if let Ok((Overview::Arena(data), _)) =
+            with_rank_fallback(self.rank, &partner_champ.name, |rank| {
+                self.api.get_stats(
+                    partner_champ,
+                    self.effective_role(),
+                    self.region,
+                    self.mode,
+                    self.build,
+                    rank,
+                )
+            })
+        {
+            self.partner_synergies = data.champion_synergies;
+        }
+    }
 }
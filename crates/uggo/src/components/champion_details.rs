@@ -0,0 +1,254 @@
+use ddragon::models::champion::{Champion, Spell};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+};
+use ugg_types::markup::strip_markup;
+use ugg_types::overview::Overview;
+
+use crate::components::ability_order;
+use crate::context::{AppContext, State};
+use crate::layout::STACKED_WIDTH;
+use crate::theme;
+use crate::util;
+
+/// Base stats shown in the table: label, stat key, per-level stat key and unit.
+const BASE_STATS: [(&str, &str, Option<&str>, &str); 10] = [
+    ("Health", "hp", Some("hpperlevel"), ""),
+    ("Health Regen", "hpregen", Some("hpregenperlevel"), ""),
+    ("Resource", "mp", Some("mpperlevel"), ""),
+    ("Resource Regen", "mpregen", Some("mpregenperlevel"), ""),
+    ("Armor", "armor", Some("armorperlevel"), ""),
+    ("Magic Resist", "spellblock", Some("spellblockperlevel"), ""),
+    (
+        "Attack Damage",
+        "attackdamage",
+        Some("attackdamageperlevel"),
+        "",
+    ),
+    (
+        "Attack Speed",
+        "attackspeed",
+        Some("attackspeedperlevel"),
+        "%",
+    ),
+    ("Move Speed", "movespeed", None, ""),
+    ("Attack Range", "attackrange", None, ""),
+];
+
+const STATS_WIDTH: u16 = 36;
+
+fn make_block<'a>(title: &str) -> Block<'a> {
+    Block::default()
+        .fg(theme::current().text)
+        .title(format!(" {title} "))
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL)
+}
+
+fn make_stats<'a>(champ: &Champion) -> Table<'a> {
+    let rows = BASE_STATS
+        .iter()
+        .filter_map(|(label, key, per_level, unit)| {
+            let base = champ.stats.get(*key).copied().unwrap_or_default();
+            let growth = per_level
+                .and_then(|k| champ.stats.get(k))
+                .copied()
+                .unwrap_or_default();
+            // Resourceless champions have no mana to speak of.
+            if base == 0.0 && growth == 0.0 {
+                return None;
+            }
+            let label = match (*key, champ.partype.as_str()) {
+                ("mp", partype) if !partype.is_empty() => partype.to_string(),
+                ("mpregen", partype) if !partype.is_empty() => format!("{partype} Regen"),
+                _ => (*label).to_string(),
+            };
+            let growth = if growth == 0.0 {
+                String::new()
+            } else {
+                format!("+{}{unit}", util::format_number(growth))
+            };
+            Some(Row::new(vec![
+                Cell::from(label),
+                Cell::from(Line::from(util::format_number(base)).alignment(Alignment::Right)),
+                Cell::from(growth).fg(theme::current().muted),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    Table::new(
+        rows,
+        [
+            Constraint::Length(15),
+            Constraint::Length(7),
+            Constraint::Length(10),
+        ],
+    )
+    .column_spacing(1)
+    .style(Style::default().fg(theme::current().text))
+    .block(make_block("Base Stats"))
+}
+
+/// Cooldown, cost and range, e.g. `Cooldown 7s · Cost 55/60/65/70/75 Mana · Range 880`.
+fn format_spell_info(spell: &Spell, partype: &str) -> String {
+    let mut parts = vec![format!("Cooldown {}s", spell.cooldown_burn)];
+    if spell.cost_burn != "0" {
+        parts.push(
+            format!("Cost {} {partype}", spell.cost_burn)
+                .trim_end()
+                .to_string(),
+        );
+    }
+    if spell.range_burn != "self" {
+        parts.push(format!("Range {}", spell.range_burn));
+    }
+    parts.join(" · ")
+}
+
+fn ability_lines(
+    key: &str,
+    color: Color,
+    name: &str,
+    note: Option<String>,
+    info: Option<String>,
+    description: &str,
+) -> Vec<Line<'static>> {
+    let mut title = vec![
+        Span::styled(format!("{key} "), Style::default().fg(color).bold()),
+        Span::styled(name.to_string(), Style::default().bold()),
+    ];
+    if let Some(note) = note {
+        title.push(Span::styled(
+            format!(" ({note})"),
+            Style::default().fg(theme::current().muted),
+        ));
+    }
+
+    let mut lines = vec![Line::from(title)];
+    if let Some(info) = info {
+        lines.push(Line::from(info).fg(theme::current().info));
+    }
+    lines.push(Line::from(strip_markup(description)));
+    lines.push(Line::default());
+    lines
+}
+
+fn make_abilities<'a>(champ: &Champion, max_order: &str, scroll: u16) -> Paragraph<'a> {
+    let theme = theme::current();
+    let mut lines = ability_lines(
+        "P",
+        theme.muted,
+        &champ.passive.name,
+        Some("passive".to_string()),
+        None,
+        &champ.passive.description,
+    );
+    for ((spell, key), color) in champ
+        .spells
+        .iter()
+        .zip(['Q', 'W', 'E', 'R'])
+        .zip(theme.abilities)
+    {
+        lines.extend(ability_lines(
+            &key.to_string(),
+            color,
+            &spell.name,
+            ability_order::max_priority(max_order, key),
+            Some(format_spell_info(spell, &champ.partype)),
+            &spell.description,
+        ));
+    }
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0))
+        .style(Style::default().fg(theme.text))
+        .block(make_block("Abilities"))
+}
+
+/// Draws the selected champion's abilities and base stats.
+pub fn render(frame: &mut Frame, ctx: &AppContext, area: Rect) {
+    let Some(champ) = &ctx.selected_champ_details else {
+        frame.render_widget(
+            Paragraph::new(" Select a champion to see its abilities and base stats.")
+                .fg(theme::current().warning),
+            area,
+        );
+        return;
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(area);
+    let tags = champ
+        .tags
+        .iter()
+        .map(|t| util::tag_name(*t))
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                format!(" {}, {}", champ.name, champ.title),
+                Style::default().fg(theme::current().good).bold(),
+            ),
+            Span::styled(
+                format!("  {}  (↑/↓ to scroll, Esc to close)", tags.join(", ")),
+                Style::default().fg(theme::current().muted),
+            ),
+        ])),
+        layout[0],
+    );
+
+    let stats_height = u16::try_from(BASE_STATS.len()).unwrap_or_default() + 2;
+    let columns = if area.width < STACKED_WIDTH {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(stats_height), Constraint::Min(0)])
+            .split(layout[1])
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(STATS_WIDTH), Constraint::Min(0)])
+            .split(layout[1])
+    };
+
+    let max_order = ctx
+        .selected_champ_overview
+        .as_ref()
+        .map(Overview::abilities)
+        .map(|a| a.ability_max_order)
+        .unwrap_or_default();
+    frame.render_widget(make_stats(champ), columns[0]);
+    frame.render_widget(
+        make_abilities(champ, &max_order, ctx.champion_details_scroll),
+        columns[1],
+    );
+}
+
+impl AppContext<'_> {
+    /// The selected champion's Q/W/E/R names, or none if its details couldn't be loaded.
+    pub fn spell_names(&self) -> Vec<String> {
+        self.selected_champ_details
+            .as_ref()
+            .map(|champ| champ.spells.iter().map(|s| s.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn open_champion_details(&mut self) {
+        self.champion_details_scroll = 0;
+        self.state = State::ChampionDetails;
+    }
+
+    pub fn scroll_champion_details_down(&mut self) {
+        self.champion_details_scroll = self.champion_details_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_champion_details_up(&mut self) {
+        self.champion_details_scroll = self.champion_details_scroll.saturating_sub(1);
+    }
+}
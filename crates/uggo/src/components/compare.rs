@@ -0,0 +1,436 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use ugg_types::{
+    overview::{self, Overview},
+    stat_shards::CDragonPerk,
+};
+
+use crate::components::items;
+use crate::context::AppContext;
+use crate::keymap::Action;
+use crate::theme;
+use crate::util;
+
+/// A labelled group of runes, spells or items, compared by ID against the group with the same
+/// label in the other build.
+#[derive(Debug, Clone, Default)]
+pub struct Entries {
+    pub label: String,
+    pub entries: Vec<(i64, String)>,
+}
+
+impl Entries {
+    fn new(label: &str, entries: Vec<(i64, String)>) -> Self {
+        Self {
+            label: label.to_string(),
+            entries,
+        }
+    }
+}
+
+/// One side of the compare view. Names are resolved when it's taken, so a pinned build survives
+/// switching to a patch with different item and rune data.
+#[derive(Debug, Clone, Default)]
+pub struct BuildSnapshot {
+    pub champion: String,
+    /// Mode, role, build, region and patch, compared one by one.
+    pub settings: Vec<String>,
+    pub wins: i64,
+    pub matches: i64,
+    /// Rune trees, shards and summoner spells.
+    pub runes: Vec<Entries>,
+    pub items: Vec<Entries>,
+    pub ability_order: Vec<char>,
+    pub ability_max_order: String,
+}
+
+impl BuildSnapshot {
+    /// The selected champion's build, if its overview was loaded.
+    pub fn new(ctx: &AppContext) -> Option<Self> {
+        let champ = ctx.selected_champ.as_ref()?;
+        let overview = ctx.selected_champ_overview.as_ref()?;
+
+        let named_items = |label: &str, ids: Vec<i64>| {
+            Entries::new(
+                label,
+                ids.into_iter()
+                    .filter_map(|id| {
+                        ctx.api
+                            .items
+                            .get(&id.to_string())
+                            .map(|item| (id, item.name.clone()))
+                    })
+                    .collect(),
+            )
+        };
+
+        let (runes, items) = match overview {
+            Overview::Default(d) => {
+                let mut runes = util::group_runes(&d.runes.rune_ids, &ctx.api.runes)
+                    .into_iter()
+                    .map(|(tree, runes)| Entries {
+                        label: tree,
+                        entries: runes
+                            .iter()
+                            .map(|(id, rune)| (*id, rune.rune.name.clone()))
+                            .collect(),
+                    })
+                    .collect::<Vec<_>>();
+                runes.push(Entries::new(
+                    "Shards",
+                    d.shards
+                        .shard_ids
+                        .iter()
+                        .map(|id| {
+                            let name =
+                                ctx.api.stat_shards.shards.get(id).map_or_else(
+                                    || "Unknown".to_string(),
+                                    CDragonPerk::description,
+                                );
+                            (*id, name)
+                        })
+                        .collect(),
+                ));
+                runes.push(Entries::new(
+                    "Spells",
+                    d.summoner_spells
+                        .spell_ids
+                        .iter()
+                        .filter_map(|id| {
+                            ctx.api
+                                .summoner_spells
+                                .get(id)
+                                .map(|name| (*id, name.clone()))
+                        })
+                        .collect(),
+                ));
+
+                let [starting, core, fourth, fifth, sixth] =
+                    items::default_item_ids(d, &ctx.api.items);
+                let items = vec![
+                    named_items("Start", starting),
+                    named_items("Core", core),
+                    named_items("4th", fourth),
+                    named_items("5th", fifth),
+                    named_items("6th", sixth),
+                ];
+                (runes, items)
+            }
+            Overview::Arena(a) => {
+                let [starting, core, fourth, fifth, sixth, _, prismatic] =
+                    items::arena_item_ids(a, &ctx.api.items);
+                let items = vec![
+                    named_items("Start", starting),
+                    named_items("2nd/3rd", core),
+                    named_items("4th", fourth),
+                    named_items("5th", fifth),
+                    named_items("6th", sixth),
+                    named_items("Prismatic", prismatic),
+                ];
+                (Vec::new(), items)
+            }
+        };
+
+        let abilities = overview.abilities();
+        Some(Self {
+            champion: champ.name.clone(),
+            settings: vec![
+                ctx.mode.to_string(),
+                ctx.selected_champ_role.unwrap_or(ctx.role).to_string(),
+                ctx.build.to_string(),
+                ctx.region.to_string(),
+                ctx.version.clone(),
+            ],
+            wins: overview.wins(),
+            matches: overview.matches(),
+            runes,
+            items,
+            ability_order: abilities.ability_order,
+            ability_max_order: abilities.ability_max_order,
+        })
+    }
+
+    fn win_rate(&self) -> f64 {
+        overview::winrate(self.wins, self.matches)
+    }
+}
+
+fn style(differs: bool) -> Style {
+    if differs {
+        Style::default().fg(theme::current().warning).bold()
+    } else {
+        Style::default().fg(theme::current().text)
+    }
+}
+
+/// A `Label: a, b, c` line with the entries missing from `other` highlighted. Without another
+/// build to compare with, nothing is highlighted.
+fn entries_line(entries: &Entries, other: Option<&[Entries]>) -> Line<'static> {
+    let matching = other.map(|other| other.iter().find(|e| e.label == entries.label));
+    let differs = |id: i64| {
+        matching.is_some_and(|m| m.is_none_or(|m| m.entries.iter().all(|(other, _)| *other != id)))
+    };
+
+    let mut spans = vec![Span::styled(
+        format!("{}: ", entries.label),
+        style(matching.is_some_and(|m| m.is_none())).bold(),
+    )];
+    for (idx, (id, name)) in entries.entries.iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::styled(", ", style(false)));
+        }
+        spans.push(Span::styled(name.clone(), style(differs(*id))));
+    }
+    Line::from(spans)
+}
+
+/// The lines of each section, top to bottom: champion and stats, runes and spells, items, and
+/// ability order. Empty sections are left out.
+fn make_sections(build: &BuildSnapshot, other: Option<&BuildSnapshot>) -> Vec<Vec<Line<'static>>> {
+    let mut header = vec![Span::styled(
+        build.champion.clone(),
+        style(other.is_some_and(|o| o.champion != build.champion)),
+    )];
+    for (idx, setting) in build.settings.iter().enumerate() {
+        let differs = other.is_some_and(|o| o.settings.get(idx) != Some(setting));
+        header.push(Span::styled(
+            " · ",
+            Style::default().fg(theme::current().muted),
+        ));
+        header.push(Span::styled(setting.clone(), style(differs)));
+    }
+    let better = other.is_some_and(|o| build.win_rate() > o.win_rate());
+    let stats = Line::from(vec![
+        Span::styled(
+            format!("{:.2}% WR", build.win_rate() * 100.0),
+            if better {
+                Style::default().fg(theme::current().good).bold()
+            } else {
+                style(false)
+            },
+        ),
+        Span::styled(
+            format!(", {} matches", build.matches),
+            Style::default().fg(theme::current().muted),
+        ),
+    ]);
+
+    let runes = build
+        .runes
+        .iter()
+        .map(|e| entries_line(e, other.map(|o| o.runes.as_slice())))
+        .collect::<Vec<_>>();
+    let items = build
+        .items
+        .iter()
+        .map(|e| entries_line(e, other.map(|o| o.items.as_slice())))
+        .collect::<Vec<_>>();
+
+    let mut order = vec![Span::styled("Order: ", style(false).bold())];
+    for (level, ability) in build.ability_order.iter().enumerate() {
+        let differs = other.is_some_and(|o| o.ability_order.get(level) != Some(ability));
+        order.push(Span::styled(format!("{ability} "), style(differs)));
+    }
+    let max_order = build
+        .ability_max_order
+        .chars()
+        .map(String::from)
+        .collect::<Vec<_>>()
+        .join(" > ");
+    let abilities = vec![
+        Line::from(vec![
+            Span::styled("Max: ", style(false).bold()),
+            Span::styled(
+                max_order,
+                style(other.is_some_and(|o| o.ability_max_order != build.ability_max_order)),
+            ),
+        ]),
+        Line::from(order),
+    ];
+
+    [vec![Line::from(header), stats], runes, items, abilities]
+        .into_iter()
+        .filter(|section| !section.is_empty())
+        .collect()
+}
+
+fn make_block<'a>(title: &str) -> Block<'a> {
+    Block::default()
+        .fg(theme::current().text)
+        .title(format!(" {title} "))
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL)
+}
+
+fn make_side<'a>(
+    build: &BuildSnapshot,
+    other: Option<&BuildSnapshot>,
+    scroll: usize,
+    block: Block<'a>,
+) -> Paragraph<'a> {
+    let sections = make_sections(build, other);
+    // Like the overview, scroll by whole sections.
+    let skip = scroll.min(sections.len().saturating_sub(1));
+    let lines = sections
+        .into_iter()
+        .skip(skip)
+        .enumerate()
+        .flat_map(|(idx, section)| (idx > 0).then(Line::default).into_iter().chain(section))
+        .collect::<Vec<_>>();
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block)
+}
+
+/// Draws the pinned build next to the selected one, with differences highlighted.
+pub fn render(frame: &mut Frame, ctx: &AppContext, area: Rect) {
+    let Some(pinned) = &ctx.comparison else {
+        return;
+    };
+    let current = BuildSnapshot::new(ctx);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let mut pinned_block = make_block("Pinned");
+    if let Some(binding) = ctx.keymap.binding(Action::Compare) {
+        pinned_block = pinned_block.title_bottom(
+            Line::from(format!(" {binding} to stop comparing ")).fg(theme::current().muted),
+        );
+    }
+    frame.render_widget(
+        make_side(pinned, current.as_ref(), ctx.overview_scroll, pinned_block),
+        columns[0],
+    );
+
+    match &current {
+        Some(current) => frame.render_widget(
+            make_side(
+                current,
+                Some(pinned),
+                ctx.overview_scroll,
+                make_block("Current"),
+            ),
+            columns[1],
+        ),
+        None => frame.render_widget(
+            Paragraph::new(" Select a champion, build, region or patch to compare with.")
+                .wrap(Wrap { trim: true })
+                .fg(theme::current().warning)
+                .block(make_block("Current")),
+            columns[1],
+        ),
+    }
+}
+
+impl AppContext<'_> {
+    /// Pins the selected build to compare others against, or leaves compare mode.
+    pub fn toggle_comparison(&mut self) {
+        if self.comparison.take().is_some() {
+            log::info!("Stopped comparing builds");
+            return;
+        }
+
+        self.comparison = BuildSnapshot::new(self);
+        self.overview_scroll = 0;
+        match &self.comparison {
+            Some(pinned) => log::info!("Pinned {} for comparison", pinned.champion),
+            None => log::warn!("Select a champion before comparing builds"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(champion: &str, wins: i64, items: &[(&str, &[i64])], order: &str) -> BuildSnapshot {
+        BuildSnapshot {
+            champion: champion.to_string(),
+            settings: vec!["Ranked".to_string(), "Mid".to_string()],
+            wins,
+            matches: 100,
+            runes: Vec::new(),
+            items: items
+                .iter()
+                .map(|(label, ids)| {
+                    Entries::new(label, ids.iter().map(|id| (*id, id.to_string())).collect())
+                })
+                .collect(),
+            ability_order: order.chars().collect(),
+            ability_max_order: "QWE".to_string(),
+        }
+    }
+
+    /// The text of the spans highlighted as a difference.
+    fn differences(line: &Line) -> Vec<String> {
+        line.spans
+            .iter()
+            .filter(|span| span.style == style(true))
+            .map(|span| span.content.trim().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn entries_missing_from_the_other_build_are_highlighted() {
+        let entries = Entries::new("Core", vec![(1, "A".to_string()), (2, "B".to_string())]);
+        let other = [Entries::new("Core", vec![(2, "B".to_string())])];
+        assert_eq!(differences(&entries_line(&entries, Some(&other))), ["A"]);
+    }
+
+    #[test]
+    fn missing_label_counts_as_a_difference() {
+        let entries = Entries::new("6th", vec![(1, "A".to_string())]);
+        let other = [Entries::new("Core", vec![(1, "A".to_string())])];
+        assert_eq!(
+            differences(&entries_line(&entries, Some(&other))),
+            ["6th:", "A"]
+        );
+    }
+
+    #[test]
+    fn nothing_is_highlighted_without_a_comparison() {
+        let pinned = build("Ahri", 60, &[("Core", &[1, 2])], "QWEQ");
+        let entries = &pinned.items[0];
+        assert!(differences(&entries_line(entries, None)).is_empty());
+        for section in make_sections(&pinned, None) {
+            for line in &section {
+                assert!(differences(line).is_empty());
+                assert!(
+                    line.spans
+                        .iter()
+                        .all(|span| span.style.fg != Some(theme::current().good))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ability_order_is_compared_per_level() {
+        let pinned = build("Ahri", 50, &[], "QWEQ");
+        let current = build("Ahri", 50, &[], "QEWQ");
+        let sections = make_sections(&pinned, Some(&current));
+        let order = sections.last().unwrap().last().unwrap();
+        assert_eq!(differences(order), ["W", "E"]);
+    }
+
+    #[test]
+    fn higher_win_rate_is_marked_better() {
+        let pinned = build("Ahri", 60, &[], "Q");
+        let current = build("Lux", 50, &[], "Q");
+        let is_better = |sections: &[Vec<Line>]| {
+            sections[0][1].spans[0].style == Style::default().fg(theme::current().good).bold()
+        };
+        let pinned_sections = make_sections(&pinned, Some(&current));
+        assert!(is_better(&pinned_sections));
+        assert!(!is_better(&make_sections(&current, Some(&pinned))));
+        assert_eq!(differences(&pinned_sections[0][0]), ["Ahri"]);
+    }
+}
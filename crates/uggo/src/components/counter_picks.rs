@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use ddragon::models::champions::ChampionShort;
+use ratatui::{
+    style::{Style, Stylize},
+    widgets::{Paragraph, Widget},
+};
+use ugg_types::{client_champ_select::ChampSelectSession, mappings::Role};
+use uggo_ugg_api::with_rank_fallback;
+
+use crate::context::AppContext;
+use crate::theme;
+
+#[derive(Debug, Clone)]
+pub struct CounterPick {
+    pub champ: ChampionShort,
+    /// Our win rate against the enemy in our lane, if they're known.
+    pub lane_winrate: Option<f64>,
+    /// Our average win rate against the enemy team revealed so far.
+    pub team_winrate: Option<f64>,
+}
+
+impl CounterPick {
+    fn score(&self) -> f64 {
+        self.lane_winrate.or(self.team_winrate).unwrap_or_default()
+    }
+}
+
+fn format_winrate(winrate: Option<f64>, label: &str) -> Option<String> {
+    winrate.map(|w| format!("{:.1}% {label}", w * 100.0))
+}
+
+pub fn make(counter_picks: &[CounterPick]) -> impl Widget {
+    Paragraph::new(format!(
+        " Pick Suggestions: {}",
+        counter_picks
+            .iter()
+            .take(3)
+            .map(|p| {
+                let stats = [
+                    format_winrate(p.lane_winrate, "lane"),
+                    format_winrate(p.team_winrate, "team"),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ");
+                format!("{} ({stats})", p.champ.name)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+    .style(Style::default().fg(theme::current().good).bold())
+}
+
+impl AppContext<'_> {
+    /// The champions in our pool for `role`, looked up exactly by Data Dragon id or name.
+    fn champion_pool(&self, role: Role) -> Vec<ChampionShort> {
+        self.config
+            .settings()
+            .champion_pool
+            .iter()
+            .filter(|(pool_role, _)| Role::from_str(pool_role).is_ok_and(|r| r == role))
+            .flat_map(|(_, champs)| champs.iter())
+            .filter_map(|name| {
+                let champ = self.api.champ_data.get(name).or_else(|| {
+                    self.api
+                        .champ_data
+                        .values()
+                        .find(|c| c.name.eq_ignore_ascii_case(name))
+                });
+                if champ.is_none() {
+                    log::warn!("Unknown champion {name:?} in the {role} champion pool");
+                }
+                champ.cloned()
+            })
+            .collect()
+    }
+
+    /// Ranks the champion pool for our role against the enemies revealed in `session`. Starts
+    /// over when the enemy team or our role changed since the last call, then fetches one pool
+    /// champion per call so the UI keeps drawing in between.
+    pub fn update_counter_picks(&mut self, session: &ChampSelectSession) {
+        let enemies = session.enemy_champion_ids();
+        let role = self.effective_role();
+        if enemies != self.counter_pick_enemies || self.counter_pick_role != Some(role) {
+            self.counter_pick_enemies = enemies;
+            self.counter_pick_role = Some(role);
+            self.counter_picks.clear();
+            self.counter_pick_queue.clear();
+            if !self.counter_pick_enemies.is_empty() {
+                // Popped from the back, so reverse to fetch the pool in order.
+                self.counter_pick_queue
+                    .extend(self.champion_pool(role).into_iter().rev());
+            }
+        }
+
+        let Some(champ) = self.counter_pick_queue.pop() else {
+            return;
+        };
+        let matchups = with_rank_fallback(self.rank, &champ.name, |rank| {
+            self.api
+                .get_matchups(&champ, role, self.region, self.mode, rank)
+        });
+        let Ok((matchups, _)) = matchups.inspect_err(|e| {
+            log::warn!(
+                "Could not get {} matchups for pick suggestions: {e}",
+                champ.name
+            );
+        }) else {
+            return;
+        };
+
+        let counter_pick = CounterPick {
+            lane_winrate: session
+                .enemy_laner_id()
+                .and_then(|id| matchups.winrate_against(id)),
+            team_winrate: matchups.average_winrate_against(&self.counter_pick_enemies),
+            champ,
+        };
+        if counter_pick.lane_winrate.is_some() || counter_pick.team_winrate.is_some() {
+            self.counter_picks.push(counter_pick);
+            self.counter_picks
+                .sort_by(|a, b| b.score().total_cmp(&a.score()));
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use ddragon::models::items::Item;
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+use ugg_types::markup::strip_markup;
+
+use crate::context::{AppContext, State};
+use crate::theme;
+
+/// A popup describing an item or rune clicked in the overview.
+#[derive(Debug, Clone, Default)]
+pub struct Details {
+    pub title: String,
+    pub subtitle: String,
+    pub body: String,
+    /// Extra labelled lines below the description, e.g. an item's stats and build path.
+    pub sections: Vec<(String, String)>,
+}
+
+const DETAILS_WIDTH: u16 = 60;
+
+/// Item stats shown in the details: Data Dragon key, name and whether it's a fraction to be shown
+/// as a percentage.
+const ITEM_STATS: [(&str, &str, bool); 13] = [
+    ("FlatHPPoolMod", "Health", false),
+    ("FlatMPPoolMod", "Mana", false),
+    ("FlatHPRegenMod", "Health Regen", false),
+    ("PercentHPRegenMod", "Health Regen", true),
+    ("FlatArmorMod", "Armor", false),
+    ("FlatSpellBlockMod", "Magic Resist", false),
+    ("FlatPhysicalDamageMod", "Attack Damage", false),
+    ("FlatMagicDamageMod", "Ability Power", false),
+    ("PercentAttackSpeedMod", "Attack Speed", true),
+    ("FlatCritChanceMod", "Critical Strike Chance", true),
+    ("FlatMovementSpeedMod", "Move Speed", false),
+    ("PercentMovementSpeedMod", "Move Speed", true),
+    ("PercentLifeStealMod", "Life Steal", true),
+];
+
+/// The item's stats, e.g. `+80 Ability Power, +25% Attack Speed`, in a stable order.
+fn format_item_stats(item: &Item) -> String {
+    ITEM_STATS
+        .iter()
+        .filter_map(|(key, name, percent)| {
+            let value = item.stats.get(*key).copied().filter(|v| *v != 0.0)?;
+            Some(if *percent {
+                format!("+{}% {name}", (value * 100.0).round())
+            } else {
+                format!("+{} {name}", value.round())
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Names of the items with the given IDs, skipping unknown and duplicate ones.
+fn item_names(ids: Option<&Vec<String>>, items: &HashMap<String, Item>) -> String {
+    let mut names = Vec::new();
+    for name in ids
+        .into_iter()
+        .flatten()
+        .filter_map(|id| items.get(id).map(|item| item.name.clone()))
+    {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.join(", ")
+}
+
+/// Total gold, and for completed items the cost of combining the components, e.g.
+/// `3000g (850g to combine), sells for 2100g`.
+fn format_gold(item: &Item) -> String {
+    if !item.gold.purchasable {
+        return "Not purchasable".to_string();
+    }
+    if item.from.as_ref().is_some_and(|from| !from.is_empty()) {
+        format!(
+            "{}g ({}g to combine), sells for {}g",
+            item.gold.total, item.gold.base, item.gold.sell
+        )
+    } else {
+        format!("{}g, sells for {}g", item.gold.total, item.gold.sell)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make<'a>(details: &Details) -> (impl Widget + 'a, Rect) {
+    // Rough estimate of the wrapped height, the popup is clipped on small terminals anyway.
+    let wrapped_lines = |text: &str| text.chars().count() as u16 / (DETAILS_WIDTH - 2) + 1;
+    let body_lines = wrapped_lines(&details.body)
+        + details
+            .sections
+            .iter()
+            .map(|(label, value)| wrapped_lines(&format!("{label}: {value}")))
+            .sum::<u16>()
+        + u16::from(!details.sections.is_empty());
+
+    let mut lines = vec![
+        Line::from(details.subtitle.clone()).fg(theme::current().muted),
+        Line::default(),
+        Line::from(details.body.clone()),
+    ];
+    if !details.sections.is_empty() {
+        lines.push(Line::default());
+    }
+    for (label, value) in &details.sections {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{label}: "), Style::default().bold()),
+            Span::raw(value.clone()),
+        ]));
+    }
+    lines.push(Line::default());
+    lines.push(Line::from("Esc or click to close").fg(theme::current().muted));
+    (
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(theme::current().text))
+            .block(
+                Block::default()
+                    .title(format!(" {} ", details.title))
+                    .title_style(Style::default().bold())
+                    .borders(Borders::ALL),
+            ),
+        Rect::new(0, 0, DETAILS_WIDTH + 2, body_lines + 6),
+    )
+}
+
+impl AppContext<'_> {
+    pub fn show_item_details(&mut self, id: i64) {
+        if let Some(item) = self.api.items.get(&id.to_string()) {
+            let sections = [
+                ("Stats", format_item_stats(item)),
+                (
+                    "Builds from",
+                    item_names(item.from.as_ref(), &self.api.items),
+                ),
+                (
+                    "Builds into",
+                    item_names(item.into.as_ref(), &self.api.items),
+                ),
+            ]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(label, value)| (label.to_string(), value))
+            .collect();
+            self.details = Some(Details {
+                title: item.name.clone(),
+                subtitle: format_gold(item),
+                body: strip_markup(&item.description),
+                sections,
+            });
+            self.state = State::Details;
+        }
+    }
+
+    pub fn show_rune_details(&mut self, id: i64) {
+        if let Some(rune) = self.api.runes.get(&id) {
+            self.details = Some(Details {
+                title: rune.rune.name.clone(),
+                subtitle: rune.parent.clone(),
+                body: strip_markup(&rune.rune.long_desc),
+                sections: Vec::new(),
+            });
+            self.state = State::Details;
+        }
+    }
+
+    pub fn close_details(&mut self) {
+        self.details = None;
+        // Go back to the item or rune that was open, if it was picked with the keyboard.
+        match self.selection {
+            Some(selection) => self.state = selection.target.state(),
+            None => self.return_to_initial(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ddragon::models::{items::Gold, shared::Image};
+
+    use super::*;
+
+    fn item(name: &str, gold: Gold, from: Option<&[&str]>) -> Item {
+        Item {
+            name: name.to_string(),
+            description: String::new(),
+            colloq: String::new(),
+            plaintext: String::new(),
+            into: None,
+            image: Image {
+                full: String::new(),
+                sprite: String::new(),
+                group: String::new(),
+                x: 0,
+                y: 0,
+                w: 0,
+                h: 0,
+            },
+            gold,
+            tags: Vec::new(),
+            maps: HashMap::new(),
+            stats: HashMap::new(),
+            in_store: None,
+            from: from.map(|ids| ids.iter().map(ToString::to_string).collect()),
+            effect: None,
+            depth: None,
+            consumed: None,
+            stacks: None,
+            hide_from_all: None,
+            consume_on_full: None,
+            required_champion: None,
+            required_ally: None,
+            special_recipe: None,
+        }
+    }
+
+    const GOLD: Gold = Gold {
+        base: 850,
+        total: 3000,
+        sell: 2100,
+        purchasable: true,
+    };
+
+    #[test]
+    fn stats_show_fractions_as_percentages() {
+        let mut tooth = item("Nashor's Tooth", GOLD, None);
+        tooth.stats = HashMap::from([
+            ("PercentAttackSpeedMod".to_string(), 0.5),
+            ("FlatMagicDamageMod".to_string(), 80.0),
+            ("FlatArmorMod".to_string(), 0.0),
+        ]);
+        assert_eq!(
+            format_item_stats(&tooth),
+            "+80 Ability Power, +50% Attack Speed"
+        );
+    }
+
+    #[test]
+    fn item_names_skip_unknown_and_duplicate_ids() {
+        let items = HashMap::from([
+            ("1052".to_string(), item("Amplifying Tome", GOLD, None)),
+            ("1042".to_string(), item("Dagger", GOLD, None)),
+        ]);
+        let ids = ["1042", "1052", "1042", "9999"].map(String::from).to_vec();
+        assert_eq!(item_names(Some(&ids), &items), "Dagger, Amplifying Tome");
+        assert_eq!(item_names(None, &items), "");
+    }
+
+    #[test]
+    fn gold_shows_combine_cost_only_with_components() {
+        assert_eq!(
+            format_gold(&item("Nashor's Tooth", GOLD, Some(&["1042"]))),
+            "3000g (850g to combine), sells for 2100g"
+        );
+        assert_eq!(
+            format_gold(&item("Dagger", GOLD, Some(&[]))),
+            "3000g, sells for 2100g"
+        );
+        assert_eq!(
+            format_gold(&item("Dagger", GOLD, None)),
+            "3000g, sells for 2100g"
+        );
+        let free = Gold {
+            purchasable: false,
+            ..GOLD
+        };
+        assert_eq!(
+            format_gold(&item("Poro-Snax", free, None)),
+            "Not purchasable"
+        );
+    }
+}
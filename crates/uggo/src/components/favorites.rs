@@ -0,0 +1,65 @@
+use ddragon::models::champions::ChampionShort;
+
+use crate::context::{AppContext, State};
+
+/// The quick switch only needs the current champion and the one before it.
+const MAX_RECENT: usize = 2;
+
+impl AppContext<'_> {
+    pub fn is_favorite(&self, champ: &ChampionShort) -> bool {
+        self.config.state().favorites.contains(&champ.id)
+    }
+
+    fn save_state(&self) {
+        if let Err(e) = self.config.save_state() {
+            log::error!("Could not save favorites and recent champions: {e}");
+        }
+    }
+
+    /// Stars or unstars the selected champion.
+    pub fn toggle_favorite(&mut self) {
+        let Some(champ) = &self.selected_champ else {
+            return;
+        };
+        let favorites = &mut self.config.state_mut().favorites;
+        if let Some(pos) = favorites.iter().position(|id| *id == champ.id) {
+            favorites.remove(pos);
+            log::info!("Removed {} from favorites", champ.name);
+        } else {
+            favorites.push(champ.id.clone());
+            log::info!("Added {} to favorites", champ.name);
+        }
+        self.save_state();
+        self.update_champ_list();
+    }
+
+    /// Moves `champ` to the front of the recently viewed history.
+    pub fn remember_recent(&mut self, champ: &ChampionShort) {
+        let recent = &mut self.config.state_mut().recent_champions;
+        if recent.first() == Some(&champ.id) {
+            return;
+        }
+        recent.retain(|id| *id != champ.id);
+        recent.insert(0, champ.id.clone());
+        recent.truncate(MAX_RECENT);
+        self.save_state();
+    }
+
+    /// Switches to the previously viewed champion, so repeated presses flip between the last
+    /// two.
+    pub fn select_previous_champion(&mut self) {
+        let current = self.selected_champ.as_ref().map(|c| c.id.as_str());
+        let previous = self
+            .config
+            .state()
+            .recent_champions
+            .iter()
+            .find(|id| Some(id.as_str()) != current)
+            .and_then(|id| self.api.champ_data.get(id))
+            .cloned();
+        if let Some(champ) = previous {
+            self.select_champion(&champ);
+            self.state = State::ChampSelected;
+        }
+    }
+}
@@ -5,6 +5,8 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
 
+use crate::keymap::Keymap;
+
 macro_rules! left_cell {
     ($text:expr) => {
         Cell::from(Line::from($text).alignment(Alignment::Left))
@@ -16,82 +18,37 @@ macro_rules! right_cell {
         Cell::from(Line::from($text).alignment(Alignment::Right))
     };
 }
-#[cfg(not(target_os = "macos"))]
-macro_rules! alt_keypress {
-    ($key:literal) => {
-        concat!("Alt+", $key)
-    };
-}
 
-#[cfg(target_os = "macos")]
-macro_rules! alt_keypress {
-    ($key:literal) => {
-        concat!("Opt+", $key)
-    };
-}
-
-const CELLS: [[&str; 2]; 14] = [
-    ["Search", alt_keypress!("s")],
-    ["Champ Select", alt_keypress!("c")],
-    ["Mode Select", alt_keypress!("m")],
-    ["Role Select", alt_keypress!("r")],
-    ["Version Select", alt_keypress!("v")],
-    ["Region Select", alt_keypress!("w")],
-    ["Build Select", alt_keypress!("b")],
-    ["Hide Left Pane", alt_keypress!("h")],
-    ["Log Viewer", alt_keypress!("l")],
+/// Keys that depend on the current view and can't be rebound.
+const FIXED_CELLS: [[&str; 2]; 4] = [
+    ["Item/Rune Details", "Click"],
     ["Exit Log Viewer", "Q"],
     ["Back", "Esc"],
     ["Send", "Enter"],
-    ["Quit", "Ctrl+Q"],
-    ["Help", "?"],
 ];
 
 #[allow(clippy::cast_possible_truncation)]
-const fn left_size() -> u16 {
-    let mut max = 0u16;
-    let mut idx = 0usize;
-
-    while idx < CELLS.len() {
-        let len = CELLS[idx][0].len() as u16;
-        if len > max {
-            max = len;
-        }
-        idx += 1;
-    }
-
-    max + 1
-}
+pub fn make(keymap: &Keymap) -> (impl Widget, Rect) {
+    let cells = keymap
+        .help_entries()
+        .into_iter()
+        .map(|(label, binding)| [label.to_string(), binding])
+        .chain(FIXED_CELLS.map(|[label, key]| [label.to_string(), key.to_string()]))
+        .collect::<Vec<_>>();
+
+    let left_size = cells.iter().map(|c| c[0].len()).max().unwrap_or_default() as u16 + 1;
+    let right_size = cells.iter().map(|c| c[1].len()).max().unwrap_or_default() as u16;
+    let height = cells.len() as u16 + 1;
 
-#[allow(clippy::cast_possible_truncation)]
-const fn right_size() -> u16 {
-    let mut max = 0u16;
-    let mut idx = 0usize;
-
-    while idx < CELLS.len() {
-        let len = CELLS[idx][1].len() as u16;
-        if len > max {
-            max = len;
-        }
-        idx += 1;
-    }
-
-    max
-}
-
-const CONSTRAINTS: [Constraint; 2] = [
-    Constraint::Length(left_size()),
-    Constraint::Length(right_size()),
-];
-
-#[allow(clippy::cast_possible_truncation)]
-pub fn make() -> (impl Widget, Rect) {
     (
         Table::new(
-            CELLS
-                .iter()
-                .map(|row| Row::new(vec![left_cell!(row[0]), right_cell!(row[1])])),
-            CONSTRAINTS,
+            cells
+                .into_iter()
+                .map(|[label, key]| Row::new(vec![left_cell!(label), right_cell!(key)])),
+            [
+                Constraint::Length(left_size),
+                Constraint::Length(right_size),
+            ],
         )
         .column_spacing(2)
         .block(
@@ -100,6 +57,6 @@ pub fn make() -> (impl Widget, Rect) {
                 .title_style(Style::default().bold())
                 .borders(Borders::ALL),
         ),
-        Rect::new(0, 0, left_size() + right_size() + 3, CELLS.len() as u16 + 1),
+        Rect::new(0, 0, left_size + right_size + 3, height),
     )
 }
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use ddragon::models::items::Item;
+use ratatui::{
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Widget},
+};
+use ugg_types::{build_progress::BuildProgress, live_client_data::AllGameData};
+
+use crate::theme;
+
+fn make_in_game_block<'a>(title: &str) -> Block<'a> {
+    Block::default()
+        .fg(theme::current().text)
+        .title(format!(" {title} "))
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL)
+}
+
+fn item_name(id: i64, items: &HashMap<String, Item>) -> String {
+    items
+        .get(&id.to_string())
+        .map_or_else(|| format!("Unknown ({id})"), |i| i.name.clone())
+}
+
+fn ability_name(live: &AllGameData, ability: char) -> &str {
+    let abilities = &live.active_player.abilities;
+    match ability {
+        'Q' => &abilities.q.display_name,
+        'W' => &abilities.w.display_name,
+        'E' => &abilities.e.display_name,
+        'R' => &abilities.r.display_name,
+        _ => "",
+    }
+}
+
+fn make_skill_up_line<'a>(live: &'a AllGameData, ability_order: &[char]) -> Line<'a> {
+    let Some(ability) = live.active_player.next_ability(ability_order) else {
+        return Line::default();
+    };
+    let name = ability_name(live, ability);
+    let ability_text = if name.is_empty() {
+        ability.to_string()
+    } else {
+        format!("{ability} ({name})")
+    };
+
+    if live.active_player.skill_points_available() > 0 {
+        Line::from(format!(" ⚠ Skill point available! Level up {ability_text}"))
+            .style(Style::default().fg(theme::current().warning).bold())
+    } else {
+        Line::from(format!(" Next skill: {ability_text}"))
+            .style(Style::default().fg(theme::current().text))
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make_status<'a>(live: &'a AllGameData, ability_order: Option<&[char]>) -> impl Widget + 'a {
+    let champion = live.me().map_or("Unknown", |me| me.champion_name.as_str());
+    let game_time = live.game_data.game_time as i64;
+
+    Paragraph::new(vec![
+        Line::from(format!(
+            " In Game: {champion}, Level: {}, Gold: {:.0}, Time: {}:{:02}",
+            live.active_player.level,
+            live.active_player.current_gold,
+            game_time / 60,
+            game_time % 60
+        ))
+        .style(Style::default().fg(theme::current().good).bold()),
+        ability_order.map_or_else(Line::default, |order| make_skill_up_line(live, order)),
+    ])
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn make_progress(progress: &BuildProgress) -> impl Widget {
+    let ratio = if progress.total() == 0 {
+        0f64
+    } else {
+        progress.completed() as f64 / progress.total() as f64
+    };
+
+    Gauge::default()
+        .block(make_in_game_block("Build Progress"))
+        .gauge_style(
+            Style::default()
+                .fg(theme::current().good)
+                .bg(theme::current().background),
+        )
+        .ratio(ratio)
+        .label(format!(
+            "{}/{} items",
+            progress.completed(),
+            progress.total()
+        ))
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make_next_purchase(
+    progress: &BuildProgress,
+    live: &AllGameData,
+    items: &HashMap<String, Item>,
+) -> impl Widget {
+    let text = match progress.next {
+        Some(id) => {
+            let cost = items.get(&id.to_string()).map_or(0, |i| i.gold.total);
+            let missing = cost - live.active_player.current_gold as i64;
+            if missing > 0 {
+                Line::from(format!(
+                    "{} ({cost}g, {missing}g to go)",
+                    item_name(id, items)
+                ))
+                .fg(theme::current().text)
+            } else {
+                Line::from(format!(
+                    "{} ({cost}g, affordable now)",
+                    item_name(id, items)
+                ))
+                .fg(theme::current().good)
+            }
+        }
+        None if progress.total() > 0 => Line::from("Build complete!").fg(theme::current().good),
+        None => Line::from("No build loaded").fg(theme::current().warning),
+    };
+
+    Paragraph::new(text).block(make_in_game_block("Next Purchase"))
+}
+
+pub fn make_build_path<'a>(progress: &BuildProgress, items: &HashMap<String, Item>) -> List<'a> {
+    List::new(
+        progress
+            .path
+            .iter()
+            .map(|(id, owned)| {
+                if *owned {
+                    ListItem::new(format!("✓ {}", item_name(*id, items)))
+                        .style(Style::default().fg(theme::current().good))
+                } else {
+                    ListItem::new(format!("  {}", item_name(*id, items)))
+                        .style(Style::default().fg(theme::current().text))
+                }
+            })
+            .collect::<Vec<_>>(),
+    )
+    .block(make_in_game_block("Build Path"))
+}
+
+pub fn make_current_items<'a>(live: &AllGameData) -> List<'a> {
+    List::new(
+        live.me()
+            .map(|me| {
+                me.items
+                    .iter()
+                    .map(|i| {
+                        if i.count > 1 {
+                            ListItem::new(format!("{} x{}", i.display_name, i.count))
+                        } else {
+                            ListItem::new(i.display_name.clone())
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default(),
+    )
+    .block(make_in_game_block("Current Items"))
+}
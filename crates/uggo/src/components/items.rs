@@ -6,13 +6,15 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Widget},
 };
 use ugg_types::{
-    arena_overview::{ArenaOverviewData, PrismaticItem},
+    arena_overview::ArenaOverviewData,
     default_overview::{LateItem, OverviewData},
 };
 
+use crate::theme;
+
 fn make_item_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
@@ -58,24 +60,64 @@ fn make_list_from_item_ids<'a>(
     .block(make_item_block(name))
 }
 
-fn make_list_from_prismatic_items<'a>(
+fn make_list_from_rated_items<'a>(
     name: &str,
-    late_items: &[PrismaticItem],
+    rated_items: impl Iterator<Item = (i64, f64)>,
     items: &HashMap<String, Item>,
 ) -> List<'a> {
     List::new(
-        late_items
-            .iter()
-            .filter_map(|i| {
+        rated_items
+            .filter_map(|(id, winrate)| {
                 items
-                    .get(&i.id.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
+                    .get(&id.to_string())
+                    .map(|it| ListItem::new(format!("{} {:.1}%", it.name, winrate * 100.0)))
             })
             .collect::<Vec<_>>(),
     )
     .block(make_item_block(name))
 }
 
+fn make_list_from_rated_lateitems<'a>(
+    name: &str,
+    late_items: &[LateItem],
+    items: &HashMap<String, Item>,
+) -> List<'a> {
+    make_list_from_rated_items(name, late_items.iter().map(|i| (i.id, i.winrate())), items)
+}
+
+/// IDs of the items shown in each list, for hit-testing rows. Unknown items aren't shown.
+fn known_ids(ids: impl Iterator<Item = i64>, items: &HashMap<String, Item>) -> Vec<i64> {
+    ids.filter(|id| items.contains_key(&id.to_string()))
+        .collect()
+}
+
+/// The item IDs in the same columns as [`make_default`].
+pub fn default_item_ids(overview: &OverviewData, items: &HashMap<String, Item>) -> [Vec<i64>; 5] {
+    [
+        known_ids(overview.starting_items.item_ids.iter().copied(), items),
+        known_ids(overview.core_items.item_ids.iter().copied(), items),
+        known_ids(overview.item_4_options.iter().map(|i| i.id), items),
+        known_ids(overview.item_5_options.iter().map(|i| i.id), items),
+        known_ids(overview.item_6_options.iter().map(|i| i.id), items),
+    ]
+}
+
+/// The item IDs in the same columns as [`make_arena`].
+pub fn arena_item_ids(
+    overview: &ArenaOverviewData,
+    items: &HashMap<String, Item>,
+) -> [Vec<i64>; 7] {
+    [
+        known_ids(overview.starting_items.item_ids.iter().copied(), items),
+        known_ids(overview.core_items.item_ids.iter().copied(), items),
+        known_ids(overview.item_4_options.iter().map(|i| i.id), items),
+        known_ids(overview.item_5_options.iter().map(|i| i.id), items),
+        known_ids(overview.item_6_options.iter().map(|i| i.id), items),
+        known_ids(overview.consumables.iter().map(|i| i.id), items),
+        known_ids(overview.prismatic_items.iter().map(|i| i.id), items),
+    ]
+}
+
 pub fn make_default(overview: &OverviewData, items: &HashMap<String, Item>) -> [impl Widget; 5] {
     [
         make_list_from_item_ids("Starting Items", &overview.starting_items.item_ids, items),
@@ -86,14 +128,32 @@ pub fn make_default(overview: &OverviewData, items: &HashMap<String, Item>) -> [
     ]
 }
 
-pub fn make_arena(overview: &ArenaOverviewData, items: &HashMap<String, Item>) -> [impl Widget; 5] {
+pub fn make_arena(overview: &ArenaOverviewData, items: &HashMap<String, Item>) -> [impl Widget; 7] {
     [
-        // make_list_from_item_ids("Starting Items", &overview.starting_items.item_ids, items),
-        make_list_from_item_ids("2nd/3rd Items", &overview.core_items.item_ids, items),
-        make_list_from_lateitems("4th Items", &overview.item_4_options, items),
-        make_list_from_lateitems("5th Items", &overview.item_5_options, items),
-        make_list_from_lateitems("6th Items", &overview.item_6_options, items),
-        // make_list_from_lateitems("Consumables", &overview.consumables, items),
-        make_list_from_prismatic_items("Prismatic Items", &overview.prismatic_items, items),
+        make_list_from_item_ids(
+            &format!(
+                "Starting Items {:.1}%",
+                overview.starting_items.winrate() * 100.0
+            ),
+            &overview.starting_items.item_ids,
+            items,
+        ),
+        make_list_from_item_ids(
+            &format!(
+                "2nd/3rd Items {:.1}%",
+                overview.core_items.winrate() * 100.0
+            ),
+            &overview.core_items.item_ids,
+            items,
+        ),
+        make_list_from_rated_lateitems("4th Items", &overview.item_4_options, items),
+        make_list_from_rated_lateitems("5th Items", &overview.item_5_options, items),
+        make_list_from_rated_lateitems("6th Items", &overview.item_6_options, items),
+        make_list_from_rated_lateitems("Consumables", &overview.consumables, items),
+        make_list_from_rated_items(
+            "Prismatic Items",
+            overview.prismatic_items.iter().map(|i| (i.id, i.winrate())),
+            items,
+        ),
     ]
 }
@@ -2,10 +2,19 @@ use std::{borrow::Cow, collections::HashMap};
 
 use ddragon::models::champions::ChampionShort;
 use ratatui::{
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     widgets::{Paragraph, Widget},
 };
+use ugg_types::client_mastery;
 use ugg_types::matchups::{Matchup, MatchupData};
+use uggo_lol_client::LOLClientAPI;
+use uggo_ugg_api::with_rank_fallback;
+
+use crate::context::AppContext;
+use crate::theme;
+
+/// How many of our most-played champions to base ban suggestions on.
+const MOST_PLAYED_COUNT: usize = 3;
 
 pub fn make_matchup_row<'a>(
     title: &'a str,
@@ -38,8 +47,84 @@ pub fn make<'a>(
 ) -> [impl Widget + 'a; 2] {
     [
         make_matchup_row("Best Matchups", &matchups.best_matchups, champ_data)
-            .style(Style::default().fg(Color::Cyan).bold()),
+            .style(Style::default().fg(theme::current().info).bold()),
         make_matchup_row("Worst Matchups", &matchups.worst_matchups, champ_data)
-            .style(Style::default().fg(Color::Red).bold()),
+            .style(Style::default().fg(theme::current().bad).bold()),
     ]
 }
+
+/// Suggests banning the champions that do best against us, skipping anyone already banned or
+/// picked. `worst_matchups` is expected to be sorted worst first.
+pub fn make_ban_suggestions<'a>(
+    worst_matchups: &'a [Matchup],
+    unavailable_champion_ids: &[i64],
+    champ_data: &'a HashMap<String, ChampionShort>,
+) -> impl Widget + 'a {
+    Paragraph::new(format!(
+        " Suggested Bans: {}",
+        worst_matchups
+            .iter()
+            .filter(|m| !unavailable_champion_ids.contains(&m.champion_id))
+            .filter_map(|m| {
+                champ_data
+                    .get(&m.champion_id.to_string())
+                    .map(|c| format!("{} ({:.1}%)", c.name, (1.0 - m.winrate) * 100.0))
+            })
+            .take(3)
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+    .style(Style::default().fg(theme::current().warning).bold())
+}
+
+impl AppContext<'_> {
+    /// Gathers the worst matchups of our most-played champions in our role, keeping the lowest
+    /// win rate we have against each opponent. Fetches one champion per call so the UI keeps
+    /// drawing in between, and starts over when our role changed or nothing could be fetched.
+    pub fn update_ban_suggestions(&mut self) {
+        let role = self.effective_role();
+        if self.ban_suggestion_role != Some(role) {
+            let Some(masteries) = self
+                .client_api
+                .as_ref()
+                .and_then(LOLClientAPI::get_champion_masteries)
+            else {
+                log::warn!("Could not get champion mastery, no ban suggestions without a hover");
+                return;
+            };
+            self.ban_suggestions.clear();
+            self.ban_suggestion_queue = client_mastery::most_played(&masteries, MOST_PLAYED_COUNT);
+            self.ban_suggestion_role = Some(role);
+        }
+
+        let Some(id) = self.ban_suggestion_queue.pop() else {
+            return;
+        };
+        let matchups = self.champ_by_key.get(&id.to_string()).and_then(|champ| {
+            with_rank_fallback(self.rank, &champ.name, |rank| {
+                self.api
+                    .get_matchups(champ, role, self.region, self.mode, rank)
+            })
+            .inspect_err(|e| log::warn!("Could not get {} matchups for bans: {e}", champ.name))
+            .ok()
+        });
+
+        if let Some((matchups, _)) = matchups {
+            for matchup in matchups.worst_matchups {
+                match self
+                    .ban_suggestions
+                    .iter_mut()
+                    .find(|m| m.champion_id == matchup.champion_id)
+                {
+                    Some(m) if matchup.winrate < m.winrate => *m = matchup,
+                    Some(_) => {}
+                    None => self.ban_suggestions.push(matchup),
+                }
+            }
+            self.ban_suggestions
+                .sort_by(|a, b| a.winrate.total_cmp(&b.winrate));
+        } else if self.ban_suggestion_queue.is_empty() && self.ban_suggestions.is_empty() {
+            self.ban_suggestion_role = None;
+        }
+    }
+}
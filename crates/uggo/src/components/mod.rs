@@ -1,11 +1,19 @@
 pub mod ability_order;
 pub mod app_border;
+pub mod augment_explorer;
 pub mod augments;
 pub mod build_select;
+pub mod build_summary;
 pub mod champ_list;
 pub mod champ_name;
 pub mod champ_synergy;
+pub mod champion_details;
+pub mod compare;
+pub mod counter_picks;
+pub mod details;
+pub mod favorites;
 pub mod help_menu;
+pub mod in_game;
 pub mod items;
 pub mod matchups;
 pub mod mode_select;
@@ -13,6 +21,8 @@ pub mod region_select;
 pub mod role_select;
 pub mod rune_path;
 pub mod search;
+pub mod selection;
 pub mod shards;
 pub mod spells;
+pub mod summoner;
 pub mod version_select;
@@ -1,24 +1,25 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 use ugg_types::mappings::Mode;
 
 use crate::context::{AppContext, State};
+use crate::theme;
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
     let mode_list = List::new(
         Mode::all()
             .iter()
-            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(Color::White)))
+            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(theme::current().text)))
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme::current().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme::current().selected)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
@@ -68,6 +69,8 @@ impl AppContext<'_> {
     pub fn select_mode(&mut self) {
         if let Some(mode) = self.mode_scroll_pos.and_then(|p| Mode::all().get(p)) {
             self.mode = *mode;
+            // Modes have different sections, so the old offset means nothing.
+            self.overview_scroll = 0;
             self.state = State::Initial;
             if let Some(champ) = self.selected_champ.clone() {
                 self.select_champion(&champ);
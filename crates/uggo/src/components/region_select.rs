@@ -1,24 +1,25 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 use ugg_types::mappings::Region;
 
 use crate::context::{AppContext, State};
+use crate::theme;
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
     let region_list = List::new(
         Region::all()
             .iter()
-            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(Color::White)))
+            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(theme::current().text)))
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme::current().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme::current().selected)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
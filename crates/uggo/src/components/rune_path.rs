@@ -9,6 +9,7 @@ use ratatui::{
 };
 use ugg_types::{default_overview::OverviewData, rune::RuneExtended};
 
+use crate::theme;
 use crate::util;
 
 fn format_rune_position(rune: &RuneExtended<RuneElement>) -> String {
@@ -28,19 +29,20 @@ fn format_rune_position(rune: &RuneExtended<RuneElement>) -> String {
 }
 
 fn rune_color(name: &str) -> Color {
+    let theme = theme::current();
     match name {
-        "Precision" => Color::Yellow,
-        "Domination" => Color::Red,
-        "Sorcery" => Color::Blue,
-        "Resolve" => Color::Green,
-        "Inspiration" => Color::Cyan,
-        _ => Color::Gray,
+        "Precision" => theme.accents[0],
+        "Domination" => theme.accents[1],
+        "Sorcery" => theme.accents[2],
+        "Resolve" => theme.accents[3],
+        "Inspiration" => theme.accents[4],
+        _ => theme.neutral,
     }
 }
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(" Rune Paths ")
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
@@ -59,23 +61,37 @@ fn make_single_rune_path(
         }),
         [Constraint::Max(6), Constraint::Length(30)],
     )
-    .style(Style::default().fg(Color::White))
+    .style(Style::default().fg(theme::current().text))
     .column_spacing(1)
     .block(
         Block::default()
-            .white()
+            .fg(theme::current().text)
             .title(format!(" ● {} ", grouped_runes.0))
             .title_style(Style::default().fg(rune_color(&grouped_runes.0)).bold())
             .borders(Borders::ALL),
     )
 }
 
+/// The rune IDs in the same tables as [`make`], primary tree first.
+pub fn rune_ids(
+    overview: &OverviewData,
+    runes: &HashMap<i64, RuneExtended<RuneElement>>,
+) -> [Vec<i64>; 2] {
+    let grouped_runes = util::group_runes(&overview.runes.rune_ids, runes);
+    let ids = |idx: usize| grouped_runes[idx].1.iter().map(|(id, _)| *id).collect();
+    if grouped_runes.len() >= 2 {
+        [ids(0), ids(1)]
+    } else {
+        [vec![], vec![]]
+    }
+}
+
 pub fn make(
     overview: &OverviewData,
     runes: &HashMap<i64, RuneExtended<RuneElement>>,
 ) -> [impl Widget; 2] {
     let grouped_runes = util::group_runes(&overview.runes.rune_ids, runes);
-    
+
     // [FIX] Xử lý an toàn: nếu thiếu dữ liệu thì hiển thị bảng rỗng thay vì crash
     if grouped_runes.len() >= 2 {
         [
@@ -1,23 +1,28 @@
 use ratatui::{
     crossterm::event::{Event, KeyEvent},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     widgets::{Block, Borders, Paragraph, Widget},
 };
 use tui_input::backend::crossterm::EventHandler;
+use ugg_types::champion_search::ChampionFilter;
 
 use crate::context::{AppContext, State};
+use crate::theme;
 
-pub fn make<'a>(ctx: &'a AppContext) -> impl Widget + 'a {
+/// `width` is the inner width of the search box, used to keep the cursor end in view.
+pub fn make<'a>(ctx: &'a AppContext, width: u16) -> impl Widget + 'a {
+    let scroll = u16::try_from(ctx.input.visual_scroll(usize::from(width))).unwrap_or_default();
     Paragraph::new(ctx.input.value())
+        .scroll((0, scroll))
         .style(match ctx.state {
-            State::TextInput => Style::default().fg(Color::Green),
-            _ => Style::default().fg(Color::White),
+            State::TextInput => Style::default().fg(theme::current().selected),
+            _ => Style::default().fg(theme::current().text),
         })
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Search ")
-                .title_style(Style::default().fg(Color::White).bold()),
+                .title_style(Style::default().fg(theme::current().text).bold()),
         )
 }
 
@@ -27,15 +32,23 @@ impl AppContext<'_> {
         if !self.champ_list.is_empty() {
             self.champ_scroll_pos = Some(0);
         }
+        let Some(top) = self
+            .list_indices
+            .first()
+            .and_then(|p| self.champ_data.iter().find(|(i, _)| i == p))
+            .map(|(_, c)| c)
+            .cloned()
+        else {
+            return;
+        };
+        // Fuzzy search rarely narrows down to one champion, so also open the best match when
+        // it's spelled out or a nickname.
         if self.champ_list.len() == 1
-            && let Some(champ) = self
-                .list_indices
-                .first()
-                .and_then(|p| self.champ_data.iter().find(|(i, _)| i == p))
-                .map(|(_, c)| c)
-                .cloned()
+            || ChampionFilter::parse(self.input.value())
+                .query
+                .is_exact(&top.name, &top.id)
         {
-            self.select_champion(&champ);
+            self.select_champion(&top);
         }
     }
 
@@ -45,7 +58,7 @@ impl AppContext<'_> {
     }
 
     pub fn on_search_keypress(&mut self, key: KeyEvent) {
-        if self.input.value().len() < 17 {
+        if self.input.value().len() < 32 {
             self.input.handle_event(&Event::Key(key));
             self.update_champ_list();
         }
@@ -0,0 +1,175 @@
+use ratatui::{
+    layout::Position,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use ugg_types::markup::strip_markup;
+
+use crate::context::{AppContext, State};
+use crate::layout::IdColumn;
+use crate::theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionTarget {
+    Items,
+    Runes,
+}
+
+impl SelectionTarget {
+    pub const fn state(self) -> State {
+        match self {
+            Self::Items => State::ItemSelect,
+            Self::Runes => State::RuneSelect,
+        }
+    }
+}
+
+/// A row picked with the keyboard in the visible item or rune columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub target: SelectionTarget,
+    pub column: usize,
+    pub row: usize,
+}
+
+/// The rune preview shown while picking runes, with the cleaned short description. u.gg only
+/// reports win rates for whole rune pages, so there's none for the rune itself.
+pub fn make_rune_preview<'a>(ctx: &AppContext) -> Option<Paragraph<'a>> {
+    let rune = ctx.api.runes.get(&ctx.selected_id()?)?;
+
+    let lines = vec![
+        Line::from(strip_markup(&rune.rune.short_desc)),
+        Line::from("Enter for the full description, Esc to close").fg(theme::current().muted),
+    ];
+
+    Some(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(theme::current().text))
+            .block(
+                Block::default()
+                    .title(format!(" {} · {} ", rune.rune.name, rune.parent))
+                    .title_style(Style::default().bold())
+                    .borders(Borders::ALL),
+            ),
+    )
+}
+
+impl AppContext<'_> {
+    pub fn selection_columns(&self, target: SelectionTarget) -> &[IdColumn] {
+        match target {
+            SelectionTarget::Items => &self.click_areas.items,
+            SelectionTarget::Runes => &self.click_areas.runes,
+        }
+    }
+
+    /// Starts picking from the visible item or rune columns with the keyboard.
+    pub fn open_selection(&mut self, target: SelectionTarget) {
+        match self
+            .selection_columns(target)
+            .iter()
+            .position(|c| !c.ids.is_empty())
+        {
+            Some(column) => {
+                self.selection = Some(Selection {
+                    target,
+                    column,
+                    row: 0,
+                });
+                self.state = target.state();
+            }
+            None => log::warn!("Nothing to select, select a champion first"),
+        }
+    }
+
+    pub fn close_selection(&mut self) {
+        self.selection = None;
+        self.return_to_initial(false);
+    }
+
+    pub fn selected_id(&self) -> Option<i64> {
+        let selection = self.selection?;
+        self.selection_columns(selection.target)
+            .get(selection.column)?
+            .ids
+            .get(selection.row)
+            .copied()
+    }
+
+    pub fn show_selected_details(&mut self) {
+        if let Some(selection) = self.selection
+            && let Some(id) = self.selected_id()
+        {
+            match selection.target {
+                SelectionTarget::Items => self.show_item_details(id),
+                SelectionTarget::Runes => self.show_rune_details(id),
+            }
+        }
+    }
+
+    /// Selects the row under the mouse and opens its details.
+    pub fn click_selection(&mut self, position: Position) {
+        let Some(target) = self.selection.map(|s| s.target) else {
+            return;
+        };
+        if let Some((column, row)) = self
+            .selection_columns(target)
+            .iter()
+            .enumerate()
+            .find_map(|(c, ids)| ids.index_at(position).map(|row| (c, row)))
+        {
+            self.selection = Some(Selection {
+                target,
+                column,
+                row,
+            });
+            self.show_selected_details();
+        }
+    }
+
+    pub fn select_next_row(&mut self) {
+        if let Some(selection) = self.selection
+            && self
+                .selection_columns(selection.target)
+                .get(selection.column)
+                .is_some_and(|c| selection.row + 1 < c.ids.len())
+        {
+            self.selection = Some(Selection {
+                row: selection.row + 1,
+                ..selection
+            });
+        }
+    }
+
+    pub fn select_prev_row(&mut self) {
+        if let Some(selection) = self.selection {
+            self.selection = Some(Selection {
+                row: selection.row.saturating_sub(1),
+                ..selection
+            });
+        }
+    }
+
+    /// Moves to the next (or previous) column that isn't empty, keeping the row if possible.
+    pub fn select_next_column(&mut self, forward: bool) {
+        let Some(selection) = self.selection else {
+            return;
+        };
+        let columns = self.selection_columns(selection.target);
+        let has_ids = |c: &usize| !columns[*c].ids.is_empty();
+        let next = if forward {
+            (selection.column + 1..columns.len()).find(has_ids)
+        } else {
+            (0..selection.column).rev().find(has_ids)
+        };
+        if let Some(column) = next {
+            let row = selection.row.min(columns[column].ids.len() - 1);
+            self.selection = Some(Selection {
+                column,
+                row,
+                ..selection
+            });
+        }
+    }
+}
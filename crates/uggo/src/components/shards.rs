@@ -4,60 +4,59 @@ use ratatui::{
     text::{Line, Text},
     widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
+use ugg_types::stat_shards::{CDragonPerk, StatShards};
 
-const fn shard_color(shard: i64) -> Color {
-    match shard {
-        5001 | 5011 => Color::Green,
-        5002 => Color::Red,
-        5003 | 5013 => Color::Magenta,
-        5005 => Color::Yellow,
-        5008 => Color::Blue,
-        5010 => Color::White,
-        _ => Color::Gray,
-    }
-}
+use crate::theme;
 
-const fn shard_text(id: i64) -> &'static str {
-    match id {
-        5001 => "+10-180 Health",
-        5002 => "+6 Armor",
-        5003 => "+8 Magic Resist",
-        5005 => "+10% Attack Speed",
-        5007 => "+8 Ability Haste",
-        5008 => "+9 Adaptive Force",
-        5010 => "+2% Move Speed",
-        5011 => "65 Health",
-        5013 => "+10% Tenacity and Slow Resist",
-        _ => "Unknown",
+/// Colors a shard by the stat it grants, so new shard IDs still get a sensible color.
+fn shard_color(shard: Option<&CDragonPerk>) -> Color {
+    let theme = theme::current();
+    let name = shard.map(|s| s.name.to_lowercase()).unwrap_or_default();
+    if name.contains("health") {
+        theme.accents[3]
+    } else if name.contains("armor") {
+        theme.accents[1]
+    } else if name.contains("magic resist") || name.contains("tenacity") {
+        theme.accents[5]
+    } else if name.contains("attack speed") {
+        theme.accents[0]
+    } else if name.contains("adaptive") {
+        theme.accents[2]
+    } else if name.contains("haste") {
+        theme.accents[4]
+    } else if name.contains("move") {
+        theme.text
+    } else {
+        theme.neutral
     }
 }
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
+        .fg(theme::current().text)
         .title(" Shards & Spells ")
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
-fn make_shard_row(name: &'_ str, shard: i64) -> Row<'_> {
+fn make_shard_row(name: &str, shard: Option<&CDragonPerk>) -> Row<'static> {
     Row::new(vec![
-        Cell::from(Line::from(name).alignment(Alignment::Right)),
+        Cell::from(Line::from(name.to_string()).alignment(Alignment::Right)),
         Cell::from(Text::styled(
             "●",
             Style::default().fg(shard_color(shard)).bold(),
         )),
-        Cell::from(shard_text(shard)),
+        Cell::from(shard.map_or_else(|| "Unknown".to_string(), CDragonPerk::description)),
     ])
 }
 
-pub fn make(shards: &[i64]) -> impl Widget {
+pub fn make(shard_ids: &[i64], stat_shards: &StatShards) -> impl Widget {
     Table::new(
-        vec![
-            make_shard_row("Offense", shards[0]),
-            make_shard_row("Flex", shards[1]),
-            make_shard_row("Defense", shards[2]),
-        ],
+        shard_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| make_shard_row(stat_shards.slot_label(i), stat_shards.shards.get(id)))
+            .collect::<Vec<_>>(),
         [
             Constraint::Length(7),
             Constraint::Length(1),
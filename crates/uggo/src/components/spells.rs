@@ -1,22 +1,24 @@
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
 };
 
 use crate::context::AppContext;
+use crate::theme;
 
 fn spell_dot(spell: &'_ str) -> Span<'_> {
+    let theme = theme::current();
     Span::styled(
         " ● ",
         Style::default().fg(match spell {
-            "Flash" | "Barrier" | "Exhaust" => Color::Yellow,
-            "Ghost" | "Clarity" => Color::Blue,
-            "Cleanse" | "Dash" => Color::Cyan,
-            "Teleport" => Color::Magenta,
-            "Heal" => Color::Green,
-            "Smite" | "Ignite" => Color::Red,
-            "Mark" => Color::White,
-            _ => Color::Gray,
+            "Flash" | "Barrier" | "Exhaust" => theme.accents[0],
+            "Ghost" | "Clarity" => theme.accents[2],
+            "Cleanse" | "Dash" => theme.accents[4],
+            "Teleport" => theme.accents[5],
+            "Heal" => theme.accents[3],
+            "Smite" | "Ignite" => theme.accents[1],
+            "Mark" => theme.text,
+            _ => theme.neutral,
         }),
     )
 }
@@ -34,11 +36,11 @@ pub fn make<'a>(ctx: &'a AppContext, spells: &'a [i64]) -> Line<'a> {
         .map_or("Unknown", |s| s.as_str());
 
     Line::from(vec![
-        Span::styled("Spells:", Style::default().fg(Color::White)),
+        Span::styled("Spells:", Style::default().fg(theme::current().text)),
         spell_dot(spell_1),
-        Span::styled(spell_1, Style::default().fg(Color::White)),
-        Span::styled(" +", Style::default().fg(Color::White)),
+        Span::styled(spell_1, Style::default().fg(theme::current().text)),
+        Span::styled(" +", Style::default().fg(theme::current().text)),
         spell_dot(spell_2),
-        Span::styled(spell_2, Style::default().fg(Color::White)),
+        Span::styled(spell_2, Style::default().fg(theme::current().text)),
     ])
 }
@@ -0,0 +1,53 @@
+use ratatui::text::Line;
+use ugg_types::client_ranked::{RankedQueueStats, RankedStats};
+
+use crate::context::AppContext;
+
+fn format_queue(stats: Option<&RankedQueueStats>) -> String {
+    stats.map_or_else(|| "Unranked".to_string(), ToString::to_string)
+}
+
+pub fn make_title<'a>(ctx: &AppContext) -> Line<'a> {
+    let Some(summoner) = &ctx.summoner else {
+        return Line::default();
+    };
+
+    let mut parts = vec![format!(
+        "{} (Lv {})",
+        summoner.riot_id(),
+        summoner.summoner_level
+    )];
+    if let Some(ranked) = &ctx.ranked {
+        parts.push(format!("[Solo: {}]", format_queue(ranked.solo())));
+        parts.push(format!("[Flex: {}]", format_queue(ranked.flex())));
+        if ctx.rank.is_none()
+            && let Some(rank) = ranked.ugg_rank()
+        {
+            parts.push(format!("(Alt+k: use {rank} stats)"));
+        }
+    }
+    Line::from(format!(" {} ", parts.join(" "))).left_aligned()
+}
+
+impl AppContext<'_> {
+    pub fn refresh_summoner(&mut self) {
+        let Some(api) = self.client_api.as_ref() else {
+            return;
+        };
+        self.summoner = api.get_summoner_info();
+        self.ranked = api.get_ranked_stats();
+    }
+
+    /// Switches between u.gg's default rank filter and the tier of the logged-in account.
+    pub fn toggle_own_rank(&mut self) {
+        self.rank = match self.rank {
+            Some(_) => None,
+            None => self.ranked.as_ref().and_then(RankedStats::ugg_rank),
+        };
+        if let Some(champ) = self.selected_champ.clone() {
+            let state = self.state;
+            self.select_champion(&champ);
+            self.state = state;
+        }
+    }
+}
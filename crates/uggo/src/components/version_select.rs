@@ -1,7 +1,8 @@
 use crate::context::AppContext;
+use crate::theme;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style, Stylize},
+    style::{Modifier, Style, Stylize},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
@@ -11,13 +12,15 @@ pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
         ctx.api
             .allowed_versions
             .iter()
-            .map(|m| ListItem::new(m.ddragon.clone()).style(Style::default().fg(Color::White)))
+            .map(|m| {
+                ListItem::new(m.ddragon.clone()).style(Style::default().fg(theme::current().text))
+            })
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme::current().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme::current().selected)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
@@ -57,7 +60,17 @@ impl AppContext<'_> {
             .and_then(|p| allowed_versions.get(p))
             && self.version != version.ddragon
         {
+            let comparison = self.comparison.clone();
+            let champ = self.selected_champ.clone();
             *self = Self::new_with_version(&version.ddragon)?;
+
+            // Keep comparing the same champion across patches.
+            if comparison.is_some() {
+                self.comparison = comparison;
+                if let Some(champ) = champ.and_then(|c| self.api.champ_data.get(&c.id).cloned()) {
+                    self.select_champion(&champ);
+                }
+            }
         }
         Ok(())
     }
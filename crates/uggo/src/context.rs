@@ -3,20 +3,41 @@
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
-use ddragon::models::champions::ChampionShort;
-use ratatui::widgets::ListItem;
+use ddragon::models::{champion::Champion, champions::ChampionShort};
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::ListItem,
+};
 use tui_input::Input;
 use tui_logger::TuiWidgetState;
 use ugg_types::{
-    client_runepage::NewRunePage,
-    mappings::{Build, Mode, Region, Role},
-    matchups::MatchupData,
+    arena_overview::ChampionSynergy,
+    champion_search::ChampionFilter,
+    client_champ_select::ChampSelectSession,
+    client_champions::ClientChampion,
+    client_gameflow::GameflowPhase,
+    client_ranked::RankedStats,
+    client_runepage::RunePageBuilder,
+    client_summoner::ClientSummoner,
+    live_client_data::AllGameData,
+    mappings::{Build, Mode, Rank, Region, Role},
+    matchups::{Matchup, MatchupData},
     overview::Overview,
 };
 use uggo_config::Config;
-use uggo_lol_client::LOLClientAPI;
-use uggo_ugg_api::{UggApi, UggApiBuilder};
+use uggo_lol_client::{
+    GameflowEvent, GameflowTracker, LOLClientAPI, LiveClientAPI, ReadyCheckAcceptor,
+};
+use uggo_ugg_api::{UggApi, UggApiBuilder, with_rank_fallback};
 
+use crate::components::{
+    augment_explorer::AugmentSort, compare::BuildSnapshot, counter_picks::CounterPick,
+    details::Details, selection::Selection,
+};
+use crate::keymap::Keymap;
+use crate::layout::{ClickAreas, overview_section_count};
+use crate::theme;
 use crate::transpose::Transposable;
 use crate::util;
 
@@ -33,11 +54,21 @@ pub enum State {
     BuildSelect,
     HelpMenu,
     Logger,
+    AugmentExplorer,
+    ChatPreview,
+    Details,
+    ChampionDetails,
+    ItemSelect,
+    RuneSelect,
 }
 
 pub struct AppContext<'a> {
     pub api: UggApi,
+    pub config: Config,
     pub client_api: Option<LOLClientAPI>,
+    pub live_client_api: Option<LiveClientAPI>,
+    pub live_game: Option<AllGameData>,
+    pub show_in_game_view: bool,
     pub state: State,
     pub show_left_pane: bool,
     pub champ_scroll_pos: Option<usize>,
@@ -45,10 +76,46 @@ pub struct AppContext<'a> {
     pub champ_by_key: HashMap<String, ChampionShort>,
     pub list_indices: Vec<usize>,
     pub champ_list: Vec<ListItem<'a>>,
+    /// Champions the logged in account can pick, by key. The value is true when the champion
+    /// is only available through the free rotation.
+    pub available_champs: Option<HashMap<String, bool>>,
+    pub owned_only: bool,
     pub selected_champ: Option<ChampionShort>,
     pub selected_champ_overview: Option<Overview>,
+    /// Abilities and base stats of the selected champion.
+    pub selected_champ_details: Option<Champion>,
     pub selected_champ_role: Option<Role>,
     pub selected_champ_matchups: Option<MatchupData>,
+    pub champ_select: Option<ChampSelectSession>,
+    pub counter_picks: Vec<CounterPick>,
+    pub counter_pick_enemies: Vec<i64>,
+    pub counter_pick_role: Option<Role>,
+    /// Pool champions whose matchups haven't been fetched for the pick suggestions yet.
+    pub counter_pick_queue: Vec<ChampionShort>,
+    /// Worst matchups of our most-played champions, for bans when nothing is hovered yet.
+    pub ban_suggestions: Vec<Matchup>,
+    pub ban_suggestion_role: Option<Role>,
+    /// Most-played champions whose matchups haven't been fetched for the ban suggestions yet.
+    pub ban_suggestion_queue: Vec<i64>,
+    pub arena_partner: Option<i64>,
+    pub partner_synergies: Vec<ChampionSynergy>,
+    pub augment_filter: Input,
+    pub augment_sort: AugmentSort,
+    pub augment_column: usize,
+    pub augment_pos: usize,
+    pub overview_scroll: usize,
+    pub summoner: Option<ClientSummoner>,
+    pub ranked: Option<RankedStats>,
+    pub rank: Option<Rank>,
+    pub chat_preview: Option<String>,
+    pub details: Option<Details>,
+    pub selection: Option<Selection>,
+    pub champion_details_scroll: u16,
+    /// A build pinned to compare the selected one against.
+    pub comparison: Option<BuildSnapshot>,
+    /// Where clickable widgets were drawn in the last frame.
+    pub click_areas: ClickAreas,
+    pub keymap: Keymap,
     pub input: Input,
     pub mode: Mode,
     pub mode_scroll_pos: Option<usize>,
@@ -61,32 +128,51 @@ pub struct AppContext<'a> {
     pub build: Build,
     pub build_scroll_pos: Option<usize>,
     pub logger_state: TuiWidgetState,
-    
+
     // Auto-detect timer
     pub last_auto_detect: Instant,
-    
+    pub gameflow: GameflowTracker,
+    pub ready_check: ReadyCheckAcceptor,
+
     #[cfg(debug_assertions)]
     pub last_render_duration: Option<Duration>,
 }
 
 impl AppContext<'_> {
-    fn create(api: UggApi) -> Self {
+    fn create(api: UggApi, config: Config) -> Self {
+        theme::init(config.settings().theme);
+        let keymap = Keymap::new(&config.settings().keybindings);
+        let ready_check = ReadyCheckAcceptor::new(
+            config.settings().auto_accept.enabled,
+            Duration::from_secs(config.settings().auto_accept.delay_secs),
+        );
         let version = api.current_version.clone();
-        let version_index = api.allowed_versions.iter().position(|v| v.ddragon == version);
+        let version_index = api
+            .allowed_versions
+            .iter()
+            .position(|v| v.ddragon == version);
 
-        let mut ordered_champ_data = api.champ_data.values()
+        let mut ordered_champ_data = api
+            .champ_data
+            .values()
             .enumerate()
             .map(|(i, c)| (i, c.clone()))
             .collect::<Vec<_>>();
         ordered_champ_data.sort_by(|(_, a), (_, b)| a.name.cmp(&b.name));
 
-        let champ_by_key = api.champ_data.values()
+        let champ_by_key = api
+            .champ_data
+            .values()
             .map(|c| (c.key.clone(), c.clone()))
             .collect::<HashMap<_, _>>();
 
         let mut app_context = Self {
             api,
+            config,
             client_api: LOLClientAPI::new().ok(),
+            live_client_api: LiveClientAPI::new().ok(),
+            live_game: None,
+            show_in_game_view: true,
             state: State::Initial,
             show_left_pane: true,
             champ_scroll_pos: None,
@@ -94,11 +180,39 @@ impl AppContext<'_> {
             champ_by_key,
             list_indices: Vec::new(),
             champ_list: Vec::new(),
+            available_champs: None,
+            owned_only: false,
             input: Input::default(),
             selected_champ: None,
             selected_champ_overview: None,
+            selected_champ_details: None,
             selected_champ_role: None,
             selected_champ_matchups: None,
+            champ_select: None,
+            counter_picks: Vec::new(),
+            counter_pick_enemies: Vec::new(),
+            counter_pick_role: None,
+            counter_pick_queue: Vec::new(),
+            ban_suggestions: Vec::new(),
+            ban_suggestion_role: None,
+            ban_suggestion_queue: Vec::new(),
+            arena_partner: None,
+            partner_synergies: Vec::new(),
+            augment_filter: Input::default(),
+            augment_sort: AugmentSort::default(),
+            augment_column: 0,
+            augment_pos: 0,
+            overview_scroll: 0,
+            summoner: None,
+            ranked: None,
+            rank: None,
+            chat_preview: None,
+            details: None,
+            selection: None,
+            champion_details_scroll: 0,
+            comparison: None,
+            click_areas: ClickAreas::default(),
+            keymap,
             mode: Mode::Normal,
             mode_scroll_pos: None,
             version,
@@ -111,10 +225,13 @@ impl AppContext<'_> {
             build_scroll_pos: Build::all().iter().position(|r| r == &Build::Recommended),
             logger_state: TuiWidgetState::default(),
             last_auto_detect: Instant::now(),
+            gameflow: GameflowTracker::new(),
+            ready_check,
             #[cfg(debug_assertions)]
             last_render_duration: None,
         };
-        app_context.update_champ_list();
+        app_context.refresh_available_champs();
+        app_context.refresh_summoner();
         app_context
     }
 
@@ -124,25 +241,113 @@ impl AppContext<'_> {
             .version(version)
             .cache_dir(config.cache())
             .build()?;
-        Ok(Self::create(api))
+        Ok(Self::create(api, config))
     }
 
     pub fn new() -> anyhow::Result<Self> {
         let config = Config::new()?;
         let api = UggApiBuilder::new().cache_dir(config.cache()).build()?;
-        Ok(Self::create(api))
+        Ok(Self::create(api, config))
     }
 
     pub fn update_champ_list(&mut self) {
-        (self.list_indices, self.champ_list) = self.champ_data
+        let mut filter = ChampionFilter::parse(self.input.value());
+        // Without u.gg's role data, @role filters would hide every champion.
+        if self.api.primary_roles.is_empty() {
+            filter.roles.clear();
+        }
+        let mut matches = self
+            .champ_data
             .iter()
-            .filter(|(_, c)| {
-                c.name.to_lowercase().contains(&self.input.value().to_lowercase())
+            .filter_map(|(i, c)| {
+                let roles = self
+                    .api
+                    .primary_roles
+                    .get(&c.key)
+                    .map_or(&[][..], Vec::as_slice);
+                let tags = c
+                    .tags
+                    .iter()
+                    .map(|t| util::tag_name(*t))
+                    .collect::<Vec<_>>();
+                filter
+                    .score(&c.name, &c.id, &tags, roles)
+                    .map(|score| (score, i, c))
+            })
+            .collect::<Vec<_>>();
+        // By score, with favorites first among equal matches (so on top when not searching).
+        // Stable, so equally good matches stay alphabetical.
+        matches.sort_by_key(|(score, _, c)| {
+            (
+                std::cmp::Reverse(*score),
+                std::cmp::Reverse(self.is_favorite(c)),
+            )
+        });
+
+        (self.list_indices, self.champ_list) = matches
+            .into_iter()
+            .filter_map(|(_, i, c)| {
+                let free = self
+                    .available_champs
+                    .as_ref()
+                    .map(|available| available.get(&c.key).copied());
+                if matches!(free, Some(None)) && self.owned_only {
+                    return None;
+                }
+
+                let mut spans = Vec::new();
+                if self.is_favorite(c) {
+                    spans.push(Span::styled(
+                        "★ ",
+                        Style::default().fg(theme::current().warning),
+                    ));
+                }
+                spans.push(Span::raw(c.name.clone()));
+                if free == Some(Some(true)) {
+                    spans.push(Span::styled(
+                        " (free)",
+                        Style::default().fg(theme::current().info),
+                    ));
+                }
+                Some((i, ListItem::new(Line::from(spans))))
             })
-            .map(|(i, c)| (i, ListItem::new(c.name.clone())))
             .unzip();
     }
 
+    pub fn refresh_available_champs(&mut self) {
+        if let Some(champs) = self
+            .client_api
+            .as_ref()
+            .and_then(LOLClientAPI::get_owned_champions)
+        {
+            self.available_champs = Some(
+                champs
+                    .into_iter()
+                    .filter(ClientChampion::is_available)
+                    .map(|c| (c.id.to_string(), !c.ownership.owned))
+                    .collect(),
+            );
+        }
+        self.update_champ_list();
+    }
+
+    pub fn scroll_overview_down(&mut self) {
+        if self.overview_scroll + 1 < overview_section_count(self.mode) {
+            self.overview_scroll += 1;
+        }
+    }
+
+    pub fn scroll_overview_up(&mut self) {
+        self.overview_scroll = self.overview_scroll.saturating_sub(1);
+    }
+
+    pub fn toggle_auto_accept(&mut self) {
+        self.ready_check.enabled = !self.ready_check.enabled;
+        if let Err(e) = self.config.set_auto_accept(self.ready_check.enabled) {
+            log::error!("Could not save settings: {e}");
+        }
+    }
+
     pub fn return_to_initial(&mut self, reset_champ_scroll: bool) {
         self.state = State::Initial;
         if reset_champ_scroll {
@@ -150,44 +355,80 @@ impl AppContext<'_> {
         }
     }
 
+    /// The role to fetch data for. An explicitly chosen role wins, otherwise the one the client
+    /// assigned us in champ select is used.
+    pub fn effective_role(&self) -> Role {
+        match self
+            .champ_select
+            .as_ref()
+            .and_then(ChampSelectSession::assigned_role)
+        {
+            Some(assigned) if self.role == Role::Automatic => assigned,
+            _ => self.role,
+        }
+    }
+
     pub fn select_champion(&mut self, champ: &ChampionShort) {
+        self.remember_recent(champ);
         self.champ_scroll_pos = None;
+        self.overview_scroll = 0;
         self.selected_champ = Some(champ.clone());
-        
+        let role = self.effective_role();
+
         // Fetch data
-        (self.selected_champ_overview, self.selected_champ_role) = self.api
-            .get_stats(champ, self.role, self.region, self.mode, self.build)
+        (self.selected_champ_overview, self.selected_champ_role) =
+            with_rank_fallback(self.rank, &champ.name, |rank| {
+                self.api
+                    .get_stats(champ, role, self.region, self.mode, self.build, rank)
+            })
             .ok()
             .transpose();
 
+        if self
+            .selected_champ_details
+            .as_ref()
+            .is_none_or(|details| details.id != champ.id)
+        {
+            self.selected_champ_details = self
+                .api
+                .get_champion(champ)
+                .inspect_err(|e| log::warn!("Could not load details for {}: {e}", champ.name))
+                .ok();
+        }
+
         // Fetch matchups logic
         if self.mode == Mode::ARAM || self.mode == Mode::Arena {
             self.selected_champ_matchups = None;
         } else {
-            self.selected_champ_matchups = self.api
-                .get_matchups(champ, self.role, self.region, self.mode)
-                .map(|v| v.0)
-                .ok();
+            self.selected_champ_matchups = with_rank_fallback(self.rank, &champ.name, |rank| {
+                self.api
+                    .get_matchups(champ, role, self.region, self.mode, rank)
+            })
+            .map(|v| v.0)
+            .ok();
         }
 
         // Auto-push runes logic
-        if let Some(Overview::Default(ref overview)) = self.selected_champ_overview {
-            if let Some(ref api) = self.client_api {
-                if let Some(data) = api.get_current_rune_page() {
-                    let (primary_style_id, sub_style_id, selected_perk_ids) = util::generate_perk_array(
-                        &util::group_runes(&overview.runes.rune_ids, &self.api.runes),
-                        &overview.shards.shard_ids,
-                    );
-                    api.update_rune_page(
-                        data.id,
-                        &NewRunePage {
-                            name: format!("uggo: {}, {}", &champ.name, self.mode),
-                            primary_style_id,
-                            sub_style_id,
-                            selected_perk_ids,
-                        },
-                    );
+        if let Some(Overview::Default(ref overview)) = self.selected_champ_overview
+            && let Some(ref api) = self.client_api
+            && let Some(data) = api.get_current_rune_page()
+        {
+            match RunePageBuilder::new(&self.api.runes)
+                .name(&format!("uggo: {}, {}", &champ.name, self.mode))
+                .styles(
+                    overview.runes.primary_style_id,
+                    overview.runes.secondary_style_id,
+                )
+                .rune_ids(&overview.runes.rune_ids)
+                .shard_ids(&overview.shards.shard_ids)
+                .build()
+            {
+                Ok(page) => {
+                    if let Err(e) = api.update_rune_page(data.id, &page) {
+                        log::warn!("Could not update rune page for {}: {e}", champ.name);
+                    }
                 }
+                Err(e) => log::warn!("Not updating rune page for {}: {e}", champ.name),
             }
         }
 
@@ -199,8 +440,18 @@ impl AppContext<'_> {
         self.last_render_duration = Some(duration);
     }
 
-    // --- REFACTORED AUTO DETECT ---
-    pub fn check_champ_select_update(&mut self) {
+    pub fn clear_selection(&mut self) {
+        self.selected_champ = None;
+        self.selected_champ_overview = None;
+        self.selected_champ_details = None;
+        self.selected_champ_role = None;
+        self.selected_champ_matchups = None;
+        if self.state == State::ChampSelected {
+            self.state = State::Initial;
+        }
+    }
+
+    pub fn check_client_update(&mut self) {
         // Sử dụng constant từ util
         if self.last_auto_detect.elapsed() < Duration::from_millis(util::AUTO_DETECT_INTERVAL_MS) {
             return;
@@ -210,40 +461,138 @@ impl AppContext<'_> {
         // Nếu client_api chưa có, thử kết nối lại
         if self.client_api.is_none() {
             self.client_api = LOLClientAPI::new().ok();
+            if self.client_api.is_some() {
+                self.refresh_available_champs();
+                self.refresh_summoner();
+            }
         }
 
-        // Kiểm tra xem client có còn sống không bằng cách gọi API nhẹ
-        // Nếu không lấy được session (hoặc lỗi kết nối), ta coi như client chưa sẵn sàng
-        // Logic tách biệt để tránh borrow checker
-        let mut found_champ_id: Option<String> = None;
-
-        if let Some(client) = &self.client_api {
-            if let Some(session) = client.get_champ_select_session() {
-                // Tìm bản thân trong danh sách team
-                let me = session.my_team.iter().find(|p| p.cell_id == session.local_player_cell_id);
-                
-                if let Some(me) = me {
-                    // champion_id > 0 nghĩa là đã lock hoặc đang hover tướng
-                    if me.champion_id > 0 {
-                        found_champ_id = Some(me.champion_id.to_string());
-                    }
+        if let Some(event) = self
+            .client_api
+            .as_ref()
+            .and_then(|client| client.poll_gameflow(&mut self.gameflow))
+        {
+            self.on_gameflow_event(event);
+        }
+
+        // Only champ select and the game itself need polling, there's nothing to pick up in lobby.
+        let phase = self.gameflow.phase();
+        if phase == GameflowPhase::ReadyCheck
+            && let Some(client) = &self.client_api
+            && client.poll_ready_check(&mut self.ready_check)
+        {
+            log::info!("Accepted ready check");
+        }
+        if phase == GameflowPhase::ChampSelect {
+            self.check_champ_select_update();
+        }
+        if phase.is_in_game() {
+            self.check_live_game_update();
+        } else {
+            self.live_game = None;
+        }
+    }
+
+    fn check_live_game_update(&mut self) {
+        self.live_game = self
+            .live_client_api
+            .as_ref()
+            .and_then(LiveClientAPI::get_all_game_data);
+
+        // uggo may have been opened mid-game, so champ select was never seen.
+        if self.selected_champ.is_none()
+            && let Some(champ) = self
+                .live_game
+                .as_ref()
+                .and_then(AllGameData::me)
+                .and_then(|me| {
+                    self.champ_data
+                        .iter()
+                        .find(|(_, c)| c.name == me.champion_name)
+                })
+                .map(|(_, c)| c.clone())
+        {
+            self.select_champion(&champ);
+        }
+    }
+
+    fn on_gameflow_event(&mut self, event: GameflowEvent) {
+        log::info!("Gameflow phase changed: {} -> {}", event.from, event.to);
+
+        if event.game_ended() {
+            self.clear_selection();
+            // LP changes after every ranked game.
+            self.refresh_summoner();
+        }
+
+        // Ownership changes as the free rotation and store purchases happen, pick it up again
+        // before it's needed.
+        if event.entered(GameflowPhase::ChampSelect) {
+            self.refresh_available_champs();
+        }
+        // A ready check that was declined or timed out leaves no countdown behind.
+        if event.left(GameflowPhase::ReadyCheck) {
+            self.ready_check.reset();
+        }
+        if event.left(GameflowPhase::ChampSelect) {
+            self.champ_select = None;
+            self.counter_picks.clear();
+            self.counter_pick_enemies.clear();
+            self.counter_pick_role = None;
+            self.counter_pick_queue.clear();
+            self.ban_suggestions.clear();
+            self.ban_suggestion_role = None;
+            self.ban_suggestion_queue.clear();
+            self.arena_partner = None;
+            self.partner_synergies.clear();
+        }
+    }
+
+    fn check_champ_select_update(&mut self) {
+        self.champ_select = self
+            .client_api
+            .as_ref()
+            .and_then(LOLClientAPI::get_champ_select_session);
+
+        // champion_id > 0 nghĩa là đã lock hoặc đang hover tướng, nếu chưa thì dùng tướng
+        // đã khai báo trước (pick intent) trong lúc cấm.
+        let champ_id = self
+            .champ_select
+            .as_ref()
+            .and_then(ChampSelectSession::me)
+            .map(|me| {
+                if me.champion_id > 0 {
+                    me.champion_id
+                } else {
+                    me.champion_pick_intent
                 }
-            } else {
-                // Nếu gọi API thất bại, có thể Client đã tắt, reset client_api để lần sau init lại
-                // Tuy nhiên, vì LOLClientAPI dùng ureq sync, ta giữ nguyên để nó tự retry lần sau
-            }
+            })
+            .filter(|id| *id > 0);
+
+        if let Some(champ_id) = champ_id {
+            self.handle_auto_select_champ(&champ_id.to_string());
         }
 
-        // Thực hiện update UI nếu tìm thấy tướng
-        if let Some(champ_id) = found_champ_id {
-            self.handle_auto_select_champ(&champ_id);
+        if let Some(session) = self.champ_select.clone() {
+            if champ_id.is_some() {
+                self.ban_suggestions.clear();
+                self.ban_suggestion_role = None;
+                self.ban_suggestion_queue.clear();
+            } else if session.is_ban_phase() {
+                self.update_ban_suggestions();
+            }
+            self.update_counter_picks(&session);
+            self.update_arena_partner(&session);
         }
     }
 
     fn handle_auto_select_champ(&mut self, champ_id: &str) {
         // Kiểm tra xem có cần update không (để tránh render lại liên tục)
-        let need_update = self.selected_champ.as_ref().map_or(true, |c| c.key != champ_id);
-        
+        let need_update = self
+            .selected_champ
+            .as_ref()
+            .is_none_or(|c| c.key != champ_id);
+
         if need_update {
             // Tìm tướng trong cache dựa trên Key ID (ví dụ "266" cho Aatrox)
             if let Some(champ) = self.champ_by_key.get(champ_id).cloned() {
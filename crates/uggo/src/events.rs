@@ -1,6 +1,14 @@
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    crossterm::event::{
+        self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::Position,
+};
+use ugg_types::mappings::{Build, Mode, Region, Role};
 
+use crate::components::selection::SelectionTarget;
 use crate::context::{AppContext, State};
+use crate::keymap::Action;
 
 const fn keycode_to_logger_event(key: &event::KeyEvent) -> Option<tui_logger::TuiWidgetEvent> {
     match key.code {
@@ -20,144 +28,328 @@ const fn keycode_to_logger_event(key: &event::KeyEvent) -> Option<tui_logger::Tu
     }
 }
 
-pub fn handle_events(ctx: &mut AppContext) -> anyhow::Result<bool> {
-    if event::poll(std::time::Duration::from_millis(50))?
-        && let Event::Key(key) = event::read()?
-    {
-        // Ignore release events to fix undesired double-input issues.
-        // https://github.com/ratatui-org/ratatui/issues/347
-        if key.kind == event::KeyEventKind::Release {
-            return Ok(false);
+/// Picks the popup list entry at `idx`, as if it had been scrolled to and confirmed.
+fn select_popup_entry(ctx: &mut AppContext, idx: usize) -> anyhow::Result<()> {
+    match ctx.state {
+        State::ModeSelect if idx < Mode::all().len() => {
+            ctx.mode_scroll_pos = Some(idx);
+            ctx.select_mode();
+        }
+        State::VersionSelect if idx < ctx.api.allowed_versions.len() => {
+            ctx.version_scroll_pos = Some(idx);
+            ctx.select_version()?;
+        }
+        State::RegionSelect if idx < Region::all().len() => {
+            ctx.region_scroll_pos = Some(idx);
+            ctx.select_region();
         }
+        State::RoleSelect if idx < Role::all().len() => {
+            ctx.role_scroll_pos = Some(idx);
+            ctx.select_role();
+        }
+        State::BuildSelect if idx < Build::all().len() => {
+            ctx.build_scroll_pos = Some(idx);
+            ctx.select_build();
+        }
+        _ => {}
+    }
+    Ok(())
+}
 
-        if key.kind == event::KeyEventKind::Press
-            && key.code == KeyCode::Char('q')
-            && key.modifiers.contains(KeyModifiers::CONTROL)
+fn handle_scroll(ctx: &mut AppContext, position: Position, down: bool) {
+    match ctx.state {
+        State::ModeSelect if down => ctx.next_mode(),
+        State::ModeSelect => ctx.prev_mode(),
+        State::VersionSelect if down => ctx.next_version(),
+        State::VersionSelect => ctx.prev_version(),
+        State::RegionSelect if down => ctx.next_region(),
+        State::RegionSelect => ctx.prev_region(),
+        State::RoleSelect if down => ctx.next_role(),
+        State::RoleSelect => ctx.prev_role(),
+        State::BuildSelect if down => ctx.next_build(),
+        State::BuildSelect => ctx.prev_build(),
+        State::AugmentExplorer if down => ctx.next_augment(),
+        State::AugmentExplorer => ctx.prev_augment(),
+        State::ItemSelect | State::RuneSelect if down => ctx.select_next_row(),
+        State::ItemSelect | State::RuneSelect => ctx.select_prev_row(),
+        State::ChampionDetails if down => ctx.scroll_champion_details_down(),
+        State::ChampionDetails => ctx.scroll_champion_details_up(),
+        State::Logger => ctx.logger_state.transition(if down {
+            tui_logger::TuiWidgetEvent::DownKey
+        } else {
+            tui_logger::TuiWidgetEvent::UpKey
+        }),
+        State::HelpMenu | State::ChatPreview | State::Details => {}
+        _ if ctx
+            .click_areas
+            .champ_list
+            .is_some_and(|list| list.area.contains(position)) =>
         {
-            return Ok(true);
+            if ctx.state != State::ChampScroll || ctx.champ_scroll_pos.is_none() {
+                ctx.state = State::ChampScroll;
+                ctx.champ_scroll_pos = (!ctx.champ_list.is_empty()).then_some(0);
+            } else if down {
+                ctx.next_champ();
+            } else {
+                ctx.prev_champ();
+            }
         }
+        _ if down => ctx.scroll_overview_down(),
+        _ => ctx.scroll_overview_up(),
+    }
+}
 
-        match ctx.state {
-            State::ChampSelected | State::Initial => {
-                if key.modifiers.contains(KeyModifiers::ALT) {
-                    match key.code {
-                        KeyCode::Char('s') => {
-                            ctx.state = State::TextInput;
-                            ctx.show_left_pane = true;
-                        }
-                        KeyCode::Char('c') => {
-                            ctx.state = State::ChampScroll;
-                            ctx.show_left_pane = true;
-                            if !ctx.champ_list.is_empty() {
-                                ctx.champ_scroll_pos = Some(0);
-                            }
-                        }
-                        KeyCode::Char('m') => {
-                            ctx.state = State::ModeSelect;
-                            ctx.mode_scroll_pos = Some(ctx.mode_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('v') => {
-                            ctx.state = State::VersionSelect;
-                            ctx.version_scroll_pos =
-                                Some(ctx.version_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('w') => {
-                            ctx.state = State::RegionSelect;
-                            ctx.match_pos_to_region();
-                        }
-                        KeyCode::Char('r') => {
-                            ctx.state = State::RoleSelect;
-                            ctx.match_pos_to_role();
-                        }
-                        KeyCode::Char('b') => {
-                            ctx.state = State::BuildSelect;
-                            ctx.build_scroll_pos = Some(ctx.build_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('h') => {
-                            ctx.show_left_pane = !ctx.show_left_pane;
-                        }
-                        KeyCode::Char('l') => {
-                            ctx.state = State::Logger;
-                        }
-                        _ => {}
-                    }
-                } else {
-                    match key.code {
-                        KeyCode::Char('?') => {
-                            ctx.state = State::HelpMenu;
-                        }
-                        KeyCode::Esc | KeyCode::Enter => {}
-                        _ => {
-                            ctx.state = State::TextInput;
-                            ctx.show_left_pane = true;
-                            ctx.on_search_keypress(key);
-                        }
-                    }
-                }
+fn handle_click(ctx: &mut AppContext, position: Position) -> anyhow::Result<()> {
+    let areas = &ctx.click_areas;
+    let outside_popup = !areas.popup.is_some_and(|popup| popup.contains(position));
+    match ctx.state {
+        State::ModeSelect
+        | State::VersionSelect
+        | State::RegionSelect
+        | State::RoleSelect
+        | State::BuildSelect => {
+            if let Some(idx) = areas.popup_list.and_then(|list| list.index_at(position)) {
+                select_popup_entry(ctx, idx)?;
+            } else if outside_popup {
+                ctx.return_to_initial(false);
             }
-            State::TextInput => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(true),
-                KeyCode::Enter => ctx.on_search_submit(),
-                KeyCode::Backspace => ctx.on_search_backspace(key),
-                _ => ctx.on_search_keypress(key),
-            },
-            State::ChampScroll => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(true),
-                KeyCode::Up => ctx.prev_champ(),
-                KeyCode::Down => ctx.next_champ(),
-                KeyCode::Enter => ctx.select_champ(),
-                KeyCode::Char('s') => ctx.go_to_search(),
-                _ => {}
-            },
-            State::ModeSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_mode(),
-                KeyCode::Down => ctx.next_mode(),
-                KeyCode::Enter => ctx.select_mode(),
-                _ => {}
-            },
-            State::VersionSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_version(),
-                KeyCode::Down => ctx.next_version(),
-                KeyCode::Enter => ctx.select_version()?,
-                _ => {}
-            },
-            State::RegionSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_region(),
-                KeyCode::Down => ctx.next_region(),
-                KeyCode::Enter => ctx.select_region(),
-                _ => {}
-            },
-            State::RoleSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_role(),
-                KeyCode::Down => ctx.next_role(),
-                KeyCode::Enter => ctx.select_role(),
-                _ => {}
-            },
-            State::BuildSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_build(),
-                KeyCode::Down => ctx.next_build(),
-                KeyCode::Enter => ctx.select_build(),
-                _ => {}
-            },
-            State::HelpMenu => {
-                if let KeyCode::Esc = key.code {
-                    ctx.return_to_initial(false);
-                }
+        }
+        State::HelpMenu if outside_popup => ctx.return_to_initial(false),
+        State::ChatPreview if outside_popup => ctx.cancel_build_summary(),
+        State::Details => ctx.close_details(),
+        State::ItemSelect | State::RuneSelect => ctx.click_selection(position),
+        State::Initial | State::TextInput | State::ChampScroll | State::ChampSelected => {
+            let champ_idx = areas.champ_list.and_then(|list| list.index_at(position));
+            let item_id = areas.items.iter().find_map(|c| c.id_at(position));
+            let rune_id = areas.runes.iter().find_map(|c| c.id_at(position));
+            if areas.search.is_some_and(|search| search.contains(position)) {
+                ctx.go_to_search();
+            } else if let Some(idx) = champ_idx.filter(|idx| *idx < ctx.champ_list.len()) {
+                ctx.champ_scroll_pos = Some(idx);
+                ctx.select_champ();
+            } else if let Some(id) = item_id {
+                ctx.show_item_details(id);
+            } else if let Some(id) = rune_id {
+                ctx.show_rune_details(id);
             }
-            State::Logger => match key.code {
-                KeyCode::Char('q') => ctx.return_to_initial(false),
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_mouse_event(ctx: &mut AppContext, mouse: MouseEvent) -> anyhow::Result<()> {
+    let position = Position::new(mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::ScrollDown => handle_scroll(ctx, position, true),
+        MouseEventKind::ScrollUp => handle_scroll(ctx, position, false),
+        MouseEventKind::Down(MouseButton::Left) => handle_click(ctx, position)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn handle_events(ctx: &mut AppContext) -> anyhow::Result<bool> {
+    if event::poll(std::time::Duration::from_millis(50))? {
+        match event::read()? {
+            Event::Key(key) => return handle_key_event(ctx, key),
+            Event::Mouse(mouse) => handle_mouse_event(ctx, mouse)?,
+            _ => {}
+        }
+    }
+    Ok(false)
+}
+
+fn handle_action(ctx: &mut AppContext, action: Action) {
+    match action {
+        Action::Search => {
+            ctx.state = State::TextInput;
+            ctx.show_left_pane = true;
+        }
+        Action::ChampScroll => {
+            ctx.state = State::ChampScroll;
+            ctx.show_left_pane = true;
+            if !ctx.champ_list.is_empty() {
+                ctx.champ_scroll_pos = Some(0);
+            }
+        }
+        Action::ModeSelect => {
+            ctx.state = State::ModeSelect;
+            ctx.mode_scroll_pos = Some(ctx.mode_scroll_pos.unwrap_or_default());
+        }
+        Action::VersionSelect => {
+            ctx.state = State::VersionSelect;
+            ctx.version_scroll_pos = Some(ctx.version_scroll_pos.unwrap_or_default());
+        }
+        Action::RegionSelect => {
+            ctx.state = State::RegionSelect;
+            ctx.match_pos_to_region();
+        }
+        Action::RoleSelect => {
+            ctx.state = State::RoleSelect;
+            ctx.match_pos_to_role();
+        }
+        Action::BuildSelect => {
+            ctx.state = State::BuildSelect;
+            ctx.build_scroll_pos = Some(ctx.build_scroll_pos.unwrap_or_default());
+        }
+        Action::ToggleLeftPane => {
+            ctx.show_left_pane = !ctx.show_left_pane;
+        }
+        Action::Logger => {
+            ctx.state = State::Logger;
+        }
+        Action::InGameView => {
+            ctx.show_in_game_view = !ctx.show_in_game_view;
+        }
+        Action::OwnedOnly => ctx.toggle_owned_only(),
+        Action::AugmentExplorer => ctx.open_augment_explorer(),
+        Action::OwnRank => ctx.toggle_own_rank(),
+        Action::AutoAccept => ctx.toggle_auto_accept(),
+        Action::ShareBuild => ctx.preview_build_summary(),
+        Action::ToggleFavorite => ctx.toggle_favorite(),
+        Action::PreviousChampion => ctx.select_previous_champion(),
+        Action::Compare => ctx.toggle_comparison(),
+        Action::ChampionDetails => ctx.open_champion_details(),
+        Action::ItemSelect => ctx.open_selection(SelectionTarget::Items),
+        Action::RuneSelect => ctx.open_selection(SelectionTarget::Runes),
+        Action::ScrollDown => ctx.scroll_overview_down(),
+        Action::ScrollUp => ctx.scroll_overview_up(),
+        Action::Help => {
+            ctx.state = State::HelpMenu;
+        }
+        // Handled before dispatching on state.
+        Action::Quit => {}
+    }
+}
+
+fn handle_key_event(ctx: &mut AppContext, key: KeyEvent) -> anyhow::Result<bool> {
+    // Ignore release events to fix undesired double-input issues.
+    // https://github.com/ratatui-org/ratatui/issues/347
+    if key.kind == event::KeyEventKind::Release {
+        return Ok(false);
+    }
+
+    if key.kind == event::KeyEventKind::Press
+        && ctx
+            .keymap
+            .binding(Action::Quit)
+            .is_some_and(|binding| binding.matches(&key))
+    {
+        return Ok(true);
+    }
+
+    match ctx.state {
+        State::ChampSelected | State::Initial => match ctx.keymap.action(&key) {
+            Some(action) => handle_action(ctx, action),
+            None if key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) => {}
+            None => match key.code {
+                KeyCode::Esc | KeyCode::Enter => {}
                 _ => {
-                    if let Some(event) = keycode_to_logger_event(&key) {
-                        ctx.logger_state.transition(event);
-                    }
+                    ctx.state = State::TextInput;
+                    ctx.show_left_pane = true;
+                    ctx.on_search_keypress(key);
                 }
             },
+        },
+        State::TextInput => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(true),
+            KeyCode::Enter => ctx.on_search_submit(),
+            KeyCode::Backspace => ctx.on_search_backspace(key),
+            _ => ctx.on_search_keypress(key),
+        },
+        State::ChampScroll => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(true),
+            KeyCode::Up => ctx.prev_champ(),
+            KeyCode::Down => ctx.next_champ(),
+            KeyCode::Enter => ctx.select_champ(),
+            KeyCode::Char('s') => ctx.go_to_search(),
+            _ => {}
+        },
+        State::ModeSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_mode(),
+            KeyCode::Down => ctx.next_mode(),
+            KeyCode::Enter => ctx.select_mode(),
+            _ => {}
+        },
+        State::VersionSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_version(),
+            KeyCode::Down => ctx.next_version(),
+            KeyCode::Enter => ctx.select_version()?,
+            _ => {}
+        },
+        State::RegionSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_region(),
+            KeyCode::Down => ctx.next_region(),
+            KeyCode::Enter => ctx.select_region(),
+            _ => {}
+        },
+        State::RoleSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_role(),
+            KeyCode::Down => ctx.next_role(),
+            KeyCode::Enter => ctx.select_role(),
+            _ => {}
+        },
+        State::BuildSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_build(),
+            KeyCode::Down => ctx.next_build(),
+            KeyCode::Enter => ctx.select_build(),
+            _ => {}
+        },
+        State::AugmentExplorer => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_augment(),
+            KeyCode::Down => ctx.next_augment(),
+            KeyCode::Left => ctx.prev_augment_column(),
+            KeyCode::Right => ctx.next_augment_column(),
+            KeyCode::Tab => ctx.toggle_augment_sort(),
+            _ => ctx.on_augment_filter_keypress(key),
+        },
+        State::ItemSelect | State::RuneSelect => match key.code {
+            KeyCode::Esc => ctx.close_selection(),
+            KeyCode::Up => ctx.select_prev_row(),
+            KeyCode::Down => ctx.select_next_row(),
+            KeyCode::Left => ctx.select_next_column(false),
+            KeyCode::Right | KeyCode::Tab => ctx.select_next_column(true),
+            KeyCode::Enter => ctx.show_selected_details(),
+            _ => {}
+        },
+        State::ChampionDetails => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up | KeyCode::PageUp => ctx.scroll_champion_details_up(),
+            KeyCode::Down | KeyCode::PageDown => ctx.scroll_champion_details_down(),
+            _ => {}
+        },
+        State::ChatPreview => match key.code {
+            KeyCode::Esc => ctx.cancel_build_summary(),
+            KeyCode::Enter => ctx.send_build_summary(),
+            _ => {}
+        },
+        State::HelpMenu => {
+            if let KeyCode::Esc = key.code {
+                ctx.return_to_initial(false);
+            }
         }
+        State::Details => {
+            if let KeyCode::Esc | KeyCode::Enter = key.code {
+                ctx.close_details();
+            }
+        }
+        State::Logger => match key.code {
+            KeyCode::Char('q') => ctx.return_to_initial(false),
+            _ => {
+                if let Some(event) = keycode_to_logger_event(&key) {
+                    ctx.logger_state.transition(event);
+                }
+            }
+        },
     }
     Ok(false)
 }
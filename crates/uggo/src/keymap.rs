@@ -0,0 +1,503 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Something the user can trigger with a shortcut from the main view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Search,
+    ChampScroll,
+    ModeSelect,
+    RoleSelect,
+    VersionSelect,
+    RegionSelect,
+    BuildSelect,
+    ToggleLeftPane,
+    OwnedOnly,
+    OwnRank,
+    AutoAccept,
+    ShareBuild,
+    ToggleFavorite,
+    PreviousChampion,
+    Compare,
+    ChampionDetails,
+    ItemSelect,
+    RuneSelect,
+    InGameView,
+    AugmentExplorer,
+    ScrollUp,
+    ScrollDown,
+    Logger,
+    Quit,
+    Help,
+}
+
+/// Every action with its name in the settings file, help menu label and default shortcut, in
+/// the order shown in the help menu.
+const ACTIONS: [(Action, &str, &str, &str); 25] = [
+    (Action::Search, "search", "Search", "alt+s"),
+    (Action::ChampScroll, "champ_select", "Champ Select", "alt+c"),
+    (Action::ModeSelect, "mode_select", "Mode Select", "alt+m"),
+    (Action::RoleSelect, "role_select", "Role Select", "alt+r"),
+    (
+        Action::VersionSelect,
+        "version_select",
+        "Version Select",
+        "alt+v",
+    ),
+    (
+        Action::RegionSelect,
+        "region_select",
+        "Region Select",
+        "alt+w",
+    ),
+    (Action::BuildSelect, "build_select", "Build Select", "alt+b"),
+    (
+        Action::ToggleLeftPane,
+        "toggle_left_pane",
+        "Hide Left Pane",
+        "alt+h",
+    ),
+    (
+        Action::OwnedOnly,
+        "owned_only",
+        "Owned Champs Only",
+        "alt+o",
+    ),
+    (Action::OwnRank, "own_rank", "Use My Rank", "alt+k"),
+    (
+        Action::AutoAccept,
+        "auto_accept",
+        "Auto-accept Queue",
+        "alt+y",
+    ),
+    (
+        Action::ShareBuild,
+        "share_build",
+        "Share Build in Chat",
+        "alt+t",
+    ),
+    (
+        Action::ToggleFavorite,
+        "toggle_favorite",
+        "Favorite Champion",
+        "alt+f",
+    ),
+    (
+        Action::PreviousChampion,
+        "previous_champion",
+        "Previous Champion",
+        "alt+p",
+    ),
+    (Action::Compare, "compare", "Compare Builds", "alt+x"),
+    (
+        Action::ChampionDetails,
+        "champion_details",
+        "Champion Details",
+        "alt+d",
+    ),
+    (Action::ItemSelect, "item_select", "Item Details", "alt+i"),
+    (Action::RuneSelect, "rune_select", "Rune Details", "alt+u"),
+    (Action::InGameView, "in_game_view", "In-Game View", "alt+g"),
+    (
+        Action::AugmentExplorer,
+        "augment_explorer",
+        "Augment Explorer",
+        "alt+a",
+    ),
+    (Action::ScrollUp, "scroll_up", "Scroll Overview Up", "pgup"),
+    (
+        Action::ScrollDown,
+        "scroll_down",
+        "Scroll Overview Down",
+        "pgdn",
+    ),
+    (Action::Logger, "log_viewer", "Log Viewer", "alt+l"),
+    (Action::Quit, "quit", "Quit", "ctrl+q"),
+    (Action::Help, "help", "Help", "?"),
+];
+
+/// The name of `action` in the settings file.
+fn action_name(action: Action) -> &'static str {
+    ACTIONS
+        .iter()
+        .find(|(a, ..)| *a == action)
+        .map_or("", |(_, name, ..)| name)
+}
+
+/// macOS keyboards label Alt as Option.
+const ALT_LABEL: &str = if cfg!(target_os = "macos") {
+    "Opt+"
+} else {
+    "Alt+"
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parses shortcuts like `alt+s`, `shift+tab`, `pgdn` or `f2`. `opt`/`option` are accepted
+    /// as aliases for `alt`. Shift can't be combined with a character, since it's ignored when
+    /// matching those.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        // Allow binding the `+` key itself, e.g. `ctrl++`.
+        let (modifiers, key) = match text.strip_suffix("++") {
+            Some(rest) => (rest, "+"),
+            None if text == "+" => ("", "+"),
+            None => text.rsplit_once('+').unwrap_or(("", text)),
+        };
+
+        let mut parsed_modifiers = KeyModifiers::NONE;
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            parsed_modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "opt" | "option" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+            _ => match key.to_lowercase().as_str() {
+                "pgup" | "pageup" => KeyCode::PageUp,
+                "pgdn" | "pagedown" => KeyCode::PageDown,
+                "esc" | "escape" => KeyCode::Esc,
+                "enter" | "return" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                name => KeyCode::F(name.strip_prefix('f')?.parse().ok()?),
+            },
+        };
+
+        if matches!(code, KeyCode::Char(_)) && parsed_modifiers.contains(KeyModifiers::SHIFT) {
+            return None;
+        }
+
+        Some(Self {
+            code,
+            modifiers: parsed_modifiers,
+        })
+    }
+
+    /// Whether `key` triggers this binding. Shift is ignored for characters, since terminals
+    /// differ in whether they report it for e.g. `?`.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        match (self.code, key.code) {
+            (KeyCode::Char(expected), KeyCode::Char(actual)) => {
+                expected == actual.to_ascii_lowercase()
+                    && (self.modifiers - KeyModifiers::SHIFT)
+                        == (key.modifiers - KeyModifiers::SHIFT)
+            }
+            (expected, actual) => expected == actual && self.modifiers == key.modifiers,
+        }
+    }
+
+    /// Whether this is a plain character, which is also typed into the search box.
+    fn is_plain_character(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_))
+            && !self
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    /// Whether a key press could trigger both bindings.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.matches(&KeyEvent::new(other.code, other.modifiers))
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "{ALT_LABEL}")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            // Keep the previous `Ctrl+Q` look for letters combined with Ctrl.
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            code => write!(f, "{code}"),
+        }
+    }
+}
+
+/// Shortcuts for every [`Action`], starting from the defaults with the user's overrides from
+/// `[keybindings]` applied.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(Action, KeyBinding)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: ACTIONS
+                .iter()
+                .filter_map(|(action, _, _, default)| {
+                    KeyBinding::parse(default).map(|binding| (*action, binding))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Applies overrides keyed by action name. Invalid entries are logged and skipped. An
+    /// override takes its shortcut away from any default binding using it, but two overrides
+    /// can't share a shortcut: the one later in the help menu is skipped. Quit works in every
+    /// state, including while typing, so it needs Ctrl or Alt when bound to a character.
+    pub fn new(overrides: &HashMap<String, String>) -> Self {
+        for name in overrides.keys() {
+            if !ACTIONS.iter().any(|(_, n, ..)| n == name) {
+                log::warn!("Unknown keybinding action `{name}`");
+            }
+        }
+
+        let mut keymap = Self::default();
+        let mut overridden: Vec<(Action, &str)> = Vec::new();
+        // Go through the actions in a fixed order, so conflicts resolve the same way each run.
+        for (action, name, ..) in ACTIONS {
+            let Some(shortcut) = overrides.get(name) else {
+                continue;
+            };
+            let Some(binding) = KeyBinding::parse(shortcut) else {
+                log::warn!("Could not parse keybinding `{shortcut}` for `{name}`");
+                continue;
+            };
+            if action == Action::Quit && binding.is_plain_character() {
+                log::warn!("Keybinding `{shortcut}` for `{name}` needs Ctrl or Alt");
+                continue;
+            }
+            if let Some((_, other)) = overridden.iter().find(|(other, _)| {
+                keymap
+                    .binding(*other)
+                    .is_some_and(|existing| existing.overlaps(&binding))
+            }) {
+                log::warn!("Keybinding `{shortcut}` for `{name}` is already used by `{other}`");
+                continue;
+            }
+
+            keymap.bindings.retain(|(other, existing)| {
+                let conflicts = *other != action && existing.overlaps(&binding);
+                if conflicts {
+                    log::warn!(
+                        "Keybinding `{shortcut}` for `{name}` unbinds `{}`",
+                        action_name(*other)
+                    );
+                }
+                !conflicts
+            });
+            match keymap.bindings.iter_mut().find(|(a, _)| *a == action) {
+                Some((_, existing)) => *existing = binding,
+                None => keymap.bindings.push((action, binding)),
+            }
+            overridden.push((action, name));
+        }
+        keymap
+    }
+
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, binding)| binding.matches(key))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn binding(&self, action: Action) -> Option<KeyBinding> {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, binding)| *binding)
+    }
+
+    /// Help menu rows of label and shortcut, in a stable order.
+    pub fn help_entries(&self) -> Vec<(&'static str, String)> {
+        ACTIONS
+            .iter()
+            .filter_map(|(action, _, label, _)| {
+                self.binding(*action)
+                    .map(|binding| (*label, binding.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn overrides(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, shortcut)| ((*name).to_string(), (*shortcut).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_modifiers_and_keys() {
+        assert_eq!(
+            KeyBinding::parse("ctrl+alt+F"),
+            Some(KeyBinding {
+                code: KeyCode::Char('f'),
+                modifiers: KeyModifiers::CONTROL | KeyModifiers::ALT,
+            })
+        );
+        assert_eq!(
+            KeyBinding::parse("shift+tab"),
+            Some(KeyBinding {
+                code: KeyCode::Tab,
+                modifiers: KeyModifiers::SHIFT,
+            })
+        );
+        assert_eq!(
+            KeyBinding::parse(" pgdn "),
+            Some(KeyBinding {
+                code: KeyCode::PageDown,
+                modifiers: KeyModifiers::NONE,
+            })
+        );
+        assert_eq!(KeyBinding::parse("f2").map(|b| b.code), Some(KeyCode::F(2)));
+        assert_eq!(KeyBinding::parse("hyper+s"), None);
+        assert_eq!(KeyBinding::parse("ctrl+nope"), None);
+    }
+
+    #[test]
+    fn rejects_shift_with_characters() {
+        assert_eq!(KeyBinding::parse("ctrl+shift+f"), None);
+        assert_eq!(KeyBinding::parse("shift+?"), None);
+    }
+
+    #[test]
+    fn parses_plus_key() {
+        assert_eq!(
+            KeyBinding::parse("ctrl++"),
+            Some(KeyBinding {
+                code: KeyCode::Char('+'),
+                modifiers: KeyModifiers::CONTROL,
+            })
+        );
+        assert_eq!(
+            KeyBinding::parse("+").map(|b| b.code),
+            Some(KeyCode::Char('+'))
+        );
+    }
+
+    #[test]
+    fn opt_is_alt() {
+        let alt = KeyBinding::parse("alt+s");
+        assert_eq!(KeyBinding::parse("opt+s"), alt);
+        assert_eq!(KeyBinding::parse("Option+s"), alt);
+    }
+
+    #[test]
+    fn matches_ignore_shift_for_characters() {
+        let help = KeyBinding::parse("?").unwrap();
+        assert!(help.matches(&key(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+        assert!(help.matches(&key(KeyCode::Char('?'), KeyModifiers::NONE)));
+
+        let search = KeyBinding::parse("alt+s").unwrap();
+        assert!(search.matches(&key(
+            KeyCode::Char('S'),
+            KeyModifiers::ALT | KeyModifiers::SHIFT
+        )));
+        assert!(!search.matches(&key(KeyCode::Char('s'), KeyModifiers::NONE)));
+        assert!(!search.matches(&key(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn matches_respect_shift_for_other_keys() {
+        let tab = KeyBinding::parse("tab").unwrap();
+        assert!(tab.matches(&key(KeyCode::Tab, KeyModifiers::NONE)));
+        assert!(!tab.matches(&key(KeyCode::Tab, KeyModifiers::SHIFT)));
+    }
+
+    #[test]
+    fn displays_like_the_help_menu() {
+        assert_eq!(KeyBinding::parse("ctrl+q").unwrap().to_string(), "Ctrl+Q");
+        assert_eq!(
+            KeyBinding::parse("alt+s").unwrap().to_string(),
+            format!("{ALT_LABEL}s")
+        );
+        assert_eq!(KeyBinding::parse("ctrl++").unwrap().to_string(), "Ctrl++");
+        assert_eq!(KeyBinding::parse("pgup").unwrap().to_string(), "PgUp");
+        assert_eq!(KeyBinding::parse("f2").unwrap().to_string(), "F2");
+        assert_eq!(KeyBinding::parse("space").unwrap().to_string(), "Space");
+    }
+
+    #[test]
+    fn override_replaces_default() {
+        let keymap = Keymap::new(&overrides(&[("search", "ctrl+f")]));
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+            Some(Action::Search)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('s'), KeyModifiers::ALT)),
+            None
+        );
+    }
+
+    #[test]
+    fn override_unbinds_conflicting_default() {
+        // alt+m is Mode Select by default.
+        let keymap = Keymap::new(&overrides(&[("search", "alt+m")]));
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('m'), KeyModifiers::ALT)),
+            Some(Action::Search)
+        );
+        assert_eq!(keymap.binding(Action::ModeSelect), None);
+    }
+
+    #[test]
+    fn conflicting_overrides_keep_the_first() {
+        let keymap = Keymap::new(&overrides(&[("search", "f2"), ("mode_select", "f2")]));
+        assert_eq!(
+            keymap.action(&key(KeyCode::F(2), KeyModifiers::NONE)),
+            Some(Action::Search)
+        );
+        assert_eq!(
+            keymap.binding(Action::ModeSelect),
+            KeyBinding::parse("alt+m")
+        );
+    }
+
+    #[test]
+    fn quit_needs_a_modifier_for_characters() {
+        let keymap = Keymap::new(&overrides(&[("quit", "q")]));
+        assert_eq!(keymap.binding(Action::Quit), KeyBinding::parse("ctrl+q"));
+
+        let keymap = Keymap::new(&overrides(&[("quit", "f10")]));
+        assert_eq!(keymap.binding(Action::Quit), KeyBinding::parse("f10"));
+    }
+}
@@ -0,0 +1,92 @@
+//! Layout shared between drawing and input handling: where clickable widgets ended up, and how
+//! the overview is split into scrollable sections.
+
+use ratatui::layout::{Margin, Position, Rect};
+use ugg_types::mappings::Mode;
+
+/// A bordered list, and the index of its first visible row.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListArea {
+    pub area: Rect,
+    pub offset: usize,
+}
+
+impl ListArea {
+    /// The list index at a terminal position, if it's on one of the list's rows.
+    pub fn index_at(&self, position: Position) -> Option<usize> {
+        let inner = self.area.inner(Margin::new(1, 1));
+        inner
+            .contains(position)
+            .then(|| self.offset + usize::from(position.y - inner.y))
+    }
+}
+
+/// A bordered column listing items or runes, one per row.
+#[derive(Debug, Clone, Default)]
+pub struct IdColumn {
+    pub area: Rect,
+    pub ids: Vec<i64>,
+}
+
+impl IdColumn {
+    /// The row at a terminal position, if it's on one of the column's rows.
+    pub fn index_at(&self, position: Position) -> Option<usize> {
+        ListArea {
+            area: self.area,
+            offset: 0,
+        }
+        .index_at(position)
+        .filter(|idx| *idx < self.ids.len())
+    }
+
+    pub fn id_at(&self, position: Position) -> Option<i64> {
+        self.index_at(position)
+            .and_then(|idx| self.ids.get(idx).copied())
+    }
+
+    /// Where the row at `idx` was drawn, if it fit.
+    pub fn row_area(&self, idx: usize) -> Option<Rect> {
+        let inner = self.area.inner(Margin::new(1, 1));
+        let row = u16::try_from(idx).ok().filter(|row| *row < inner.height)?;
+        (idx < self.ids.len()).then(|| Rect::new(inner.x, inner.y + row, inner.width, 1))
+    }
+}
+
+/// Where clickable widgets were drawn in the last frame, used to hit-test mouse events.
+#[derive(Debug, Clone, Default)]
+pub struct ClickAreas {
+    pub search: Option<Rect>,
+    pub champ_list: Option<ListArea>,
+    /// The list in the mode/region/role/build/version popup.
+    pub popup_list: Option<ListArea>,
+    /// Bounds of the open popup, clicking outside of it closes it.
+    pub popup: Option<Rect>,
+    pub items: Vec<IdColumn>,
+    pub runes: Vec<IdColumn>,
+}
+
+/// Below this width, panes that sit side by side are stacked instead: runes and shards/ability
+/// order in the overview, base stats and abilities in the champion details.
+pub const STACKED_WIDTH: u16 = 76;
+
+/// Heights of the default overview sections, top to bottom: champ name, runes, shards / ability
+/// order, items, and matchups / ban or pick suggestions.
+pub const fn default_section_heights(width: u16) -> [u16; 5] {
+    if width < STACKED_WIDTH {
+        [2, 12, 12, 8, 3]
+    } else {
+        [2, 6, 6, 8, 3]
+    }
+}
+
+/// Number of sections the overview can be scrolled through in `mode`.
+pub const fn overview_section_count(mode: Mode) -> usize {
+    match mode {
+        Mode::Arena => ARENA_SECTION_HEIGHTS.len(),
+        _ => default_section_heights(0).len(),
+    }
+}
+
+/// Heights of the Arena sections, top to bottom: champ name, augments, items, and prismatic
+/// items / champ synergies / ability order / consumables.
+pub const ARENA_SECTION_HEIGHTS: [u16; 4] = [2, 8, 5, 12];
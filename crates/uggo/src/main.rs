@@ -10,6 +10,7 @@ static GLOBAL: MiMalloc = MiMalloc;
 
 use ratatui::crossterm::{
     ExecutableCommand,
+    event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::prelude::*;
@@ -21,11 +22,15 @@ use std::time::Instant;
 mod components;
 mod context;
 mod events;
+mod keymap;
+mod layout;
+mod theme;
 mod transpose;
 mod ui;
 mod util;
 
 use context::AppContext;
+use layout::ClickAreas;
 
 const HIDE_TARGETS: [&str; 13] = [
     "mio::poll",
@@ -43,6 +48,29 @@ const HIDE_TARGETS: [&str; 13] = [
     "ureq_proto::util",
 ];
 
+/// Sets the terminal up for the UI, and puts it back the way it was when dropped, including
+/// when `main` returns early with an error.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> anyhow::Result<Self> {
+        enable_raw_mode()?;
+        // Created first, so whatever was already set up is undone if a later step fails.
+        let guard = Self;
+        stdout().execute(EnterAlternateScreen)?;
+        stdout().execute(EnableMouseCapture)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = stdout().execute(DisableMouseCapture);
+        let _ = stdout().execute(LeaveAlternateScreen);
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     tui_logger::init_logger(log::LevelFilter::Info)?;
     tui_logger::set_default_level(log::LevelFilter::Trace);
@@ -50,8 +78,7 @@ fn main() -> anyhow::Result<()> {
         tui_logger::set_level_for_target(target, log::LevelFilter::Error);
     }
 
-    enable_raw_mode()?;
-    stdout().execute(EnterAlternateScreen)?;
+    let _terminal_guard = TerminalGuard::new()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
     let mut app_context = AppContext::new()?;
@@ -60,9 +87,11 @@ fn main() -> anyhow::Result<()> {
         #[cfg(debug_assertions)]
         let start_render = Instant::now();
 
-        app_context.check_champ_select_update();
+        app_context.check_client_update();
 
-        terminal.draw(|frame| ui::render(frame, &app_context))?;
+        let mut click_areas = ClickAreas::default();
+        terminal.draw(|frame| ui::render(frame, &app_context, &mut click_areas))?;
+        app_context.click_areas = click_areas;
 
         #[cfg(debug_assertions)]
         app_context.set_render_duration(start_render.elapsed());
@@ -70,7 +99,5 @@ fn main() -> anyhow::Result<()> {
         should_quit = events::handle_events(&mut app_context)?;
     }
 
-    disable_raw_mode()?;
-    stdout().execute(LeaveAlternateScreen)?;
     Ok(())
 }
@@ -0,0 +1,225 @@
+use std::ffi::OsStr;
+use std::sync::OnceLock;
+
+use ratatui::style::Color;
+use uggo_config::ThemeName;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Semantic colors used by the components, so palettes can be swapped without touching them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub text: Color,
+    pub muted: Color,
+    pub border: Color,
+    /// Fill behind popups.
+    pub background: Color,
+    /// Highlighted list entries and focused inputs.
+    pub selected: Color,
+    pub good: Color,
+    pub bad: Color,
+    pub warning: Color,
+    pub info: Color,
+    /// Q, W, E and R.
+    pub abilities: [Color; 4],
+    /// Categorical colors for rune trees, spells and shards, in the order
+    /// gold, red, blue, green, cyan, magenta for the default palette.
+    pub accents: [Color; 6],
+    /// Rune trees, spells and shards without an accent of their own.
+    pub neutral: Color,
+}
+
+impl Theme {
+    pub const DARK: Self = Self {
+        text: Color::White,
+        muted: Color::DarkGray,
+        border: Color::Magenta,
+        background: Color::Black,
+        selected: Color::Green,
+        good: Color::Green,
+        bad: Color::Red,
+        warning: Color::Yellow,
+        info: Color::Cyan,
+        abilities: [Color::Cyan, Color::Yellow, Color::Green, Color::Red],
+        accents: [
+            Color::Yellow,
+            Color::Red,
+            Color::Blue,
+            Color::Green,
+            Color::Cyan,
+            Color::Magenta,
+        ],
+        neutral: Color::Gray,
+    };
+
+    pub const LIGHT: Self = Self {
+        text: Color::Black,
+        muted: Color::Gray,
+        border: Color::Magenta,
+        background: Color::White,
+        selected: Color::Blue,
+        good: Color::Green,
+        bad: Color::Red,
+        warning: Color::Rgb(0x9a, 0x6b, 0x00),
+        info: Color::Blue,
+        abilities: [
+            Color::Blue,
+            Color::Rgb(0x9a, 0x6b, 0x00),
+            Color::Green,
+            Color::Red,
+        ],
+        accents: [
+            Color::Rgb(0x9a, 0x6b, 0x00),
+            Color::Red,
+            Color::Blue,
+            Color::Green,
+            Color::Rgb(0x00, 0x80, 0x80),
+            Color::Magenta,
+        ],
+        neutral: Color::Gray,
+    };
+
+    pub const HIGH_CONTRAST: Self = Self {
+        text: Color::White,
+        muted: Color::Gray,
+        border: Color::White,
+        background: Color::Black,
+        selected: Color::LightYellow,
+        good: Color::LightGreen,
+        bad: Color::LightRed,
+        warning: Color::LightYellow,
+        info: Color::LightCyan,
+        abilities: [
+            Color::LightCyan,
+            Color::LightYellow,
+            Color::LightGreen,
+            Color::LightRed,
+        ],
+        accents: [
+            Color::LightYellow,
+            Color::LightRed,
+            Color::LightBlue,
+            Color::LightGreen,
+            Color::LightCyan,
+            Color::LightMagenta,
+        ],
+        neutral: Color::Gray,
+    };
+
+    /// Based on the Okabe-Ito palette: good/bad are blue/orange instead of green/red.
+    pub const DEUTERANOPIA: Self = Self {
+        text: Color::White,
+        muted: Color::DarkGray,
+        border: Color::Rgb(204, 121, 167),
+        background: Color::Black,
+        selected: Color::Rgb(86, 180, 233),
+        good: Color::Rgb(0, 114, 178),
+        bad: Color::Rgb(230, 159, 0),
+        warning: Color::Rgb(240, 228, 66),
+        info: Color::Rgb(86, 180, 233),
+        abilities: [
+            Color::Rgb(86, 180, 233),
+            Color::Rgb(240, 228, 66),
+            Color::Rgb(0, 114, 178),
+            Color::Rgb(213, 94, 0),
+        ],
+        accents: [
+            Color::Rgb(240, 228, 66),
+            Color::Rgb(213, 94, 0),
+            Color::Rgb(0, 114, 178),
+            Color::Rgb(86, 180, 233),
+            Color::Rgb(0, 158, 115),
+            Color::Rgb(204, 121, 167),
+        ],
+        neutral: Color::Gray,
+    };
+
+    /// Used when `NO_COLOR` is set; emphasis falls back to bold/italic modifiers.
+    pub const MONOCHROME: Self = Self {
+        text: Color::Reset,
+        muted: Color::Reset,
+        border: Color::Reset,
+        background: Color::Reset,
+        selected: Color::Reset,
+        good: Color::Reset,
+        bad: Color::Reset,
+        warning: Color::Reset,
+        info: Color::Reset,
+        abilities: [Color::Reset; 4],
+        accents: [Color::Reset; 6],
+        neutral: Color::Reset,
+    };
+
+    #[must_use]
+    pub const fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::DARK,
+            ThemeName::Light => Self::LIGHT,
+            ThemeName::HighContrast => Self::HIGH_CONTRAST,
+            ThemeName::Deuteranopia => Self::DEUTERANOPIA,
+        }
+    }
+}
+
+/// Sets the theme for the rest of the session. `NO_COLOR` (see <https://no-color.org>)
+/// takes precedence over the configured palette.
+pub fn init(name: ThemeName) {
+    let theme = select(name, std::env::var_os("NO_COLOR").as_deref());
+    if THEME.set(theme).is_err() {
+        log::warn!("Theme was already initialized");
+    }
+}
+
+/// Any non-empty `NO_COLOR` value disables colors, an empty one is ignored.
+fn select(name: ThemeName, no_color: Option<&OsStr>) -> Theme {
+    if no_color.is_some_and(|v| !v.is_empty()) {
+        Theme::MONOCHROME
+    } else {
+        Theme::from_name(name)
+    }
+}
+
+/// The active theme, or the dark palette if [`init`] hasn't run.
+pub fn current() -> &'static Theme {
+    THEME.get().unwrap_or(&Theme::DARK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_map_to_palettes() {
+        assert_eq!(Theme::from_name(ThemeName::Dark), Theme::DARK);
+        assert_eq!(Theme::from_name(ThemeName::Light), Theme::LIGHT);
+        assert_eq!(
+            Theme::from_name(ThemeName::HighContrast),
+            Theme::HIGH_CONTRAST
+        );
+        assert_eq!(
+            Theme::from_name(ThemeName::Deuteranopia),
+            Theme::DEUTERANOPIA
+        );
+    }
+
+    #[test]
+    fn no_color_overrides_the_palette() {
+        let set = Some(OsStr::new("1"));
+        assert_eq!(select(ThemeName::Light, set), Theme::MONOCHROME);
+        assert_eq!(select(ThemeName::HighContrast, set), Theme::MONOCHROME);
+        assert_eq!(select(ThemeName::Light, Some(OsStr::new(""))), Theme::LIGHT);
+        assert_eq!(select(ThemeName::Light, None), Theme::LIGHT);
+    }
+
+    #[test]
+    fn dark_keeps_the_original_colors() {
+        let dark = Theme::DARK;
+        assert_eq!(dark.text, Color::White);
+        assert_eq!(dark.muted, Color::DarkGray);
+        assert_eq!(dark.border, Color::Magenta);
+        assert_eq!(dark.selected, Color::Green);
+        assert_eq!(dark.info, Color::Cyan);
+        assert_eq!(dark.warning, Color::Yellow);
+        assert_eq!(dark.neutral, Color::Gray);
+    }
+}
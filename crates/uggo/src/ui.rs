@@ -1,143 +1,293 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Clear, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style, Stylize},
+    widgets::{Block, Clear, Paragraph, Tabs, Wrap},
 };
 
-use ugg_types::{mappings::Mode, overview::Overview};
+use ugg_types::{
+    build_progress::BuildProgress, live_client_data::AllGameData, mappings::Mode,
+    overview::Overview,
+};
 
+use crate::components::selection::SelectionTarget;
 use crate::components::{
-    ability_order, app_border, augments, build_select, champ_list, champ_name, champ_synergy,
-    items, matchups, mode_select, region_select, role_select, rune_path, search, shards, spells,
-    version_select,
+    ability_order, app_border, augment_explorer, augments, build_select, build_summary, champ_list,
+    champ_name, champ_synergy, champion_details, compare, counter_picks, details, in_game, items,
+    matchups, mode_select, region_select, role_select, rune_path, search, selection, shards,
+    spells, version_select,
 };
 
 use crate::context::{AppContext, State};
+use crate::layout::{
+    ARENA_SECTION_HEIGHTS, ClickAreas, IdColumn, ListArea, STACKED_WIDTH, default_section_heights,
+};
+use crate::theme;
 
-const TOO_SMALL_MESSAGE: &str = "Please resize the window to at least 105x28! Ctrl+Q to exit.";
-#[allow(clippy::cast_possible_truncation)]
-const TOO_SMALL_MESSAGE_LENGTH: u16 = TOO_SMALL_MESSAGE.len() as u16;
+/// At or below this size, the compact layout is used.
+const COMPACT_WIDTH: u16 = 105;
+const COMPACT_HEIGHT: u16 = 28;
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 14;
+const TOO_SMALL_MESSAGE: &str = "Please resize the window to at least 40x14! Ctrl+Q to exit.";
 
 macro_rules! show_list_popup {
-    ($frame:expr,$ui:expr,$layout:expr) => {
+    ($frame:expr,$ui:expr,$layout:expr,$areas:expr) => {
         let (list, mut list_state, minimum_area) = $ui;
         let safe_area = $layout.inner(Margin::new(
-            ($layout.width - minimum_area.width) / 2 - 1,
-            ($layout.height - minimum_area.height) / 2 - 1,
+            ($layout.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            ($layout.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
         ));
-        $frame.render_widget(Block::new().bg(Color::Black), $layout);
+        $frame.render_widget(Block::new().bg(theme::current().background), $layout);
         $frame.render_widget(Clear, safe_area);
         $frame.render_stateful_widget(list, safe_area.inner(Margin::new(1, 1)), &mut list_state);
+        $areas.popup = Some(safe_area);
+        $areas.popup_list = Some(ListArea {
+            area: safe_area.inner(Margin::new(1, 1)),
+            offset: list_state.offset(),
+        });
     };
 }
 
-fn render_default_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect) {
+/// Splits `area` into fixed-height sections stacked top to bottom. On short terminals, whole
+/// sections are skipped from the top (up to `scroll`) instead of being squashed. Returns the
+/// area of each section, if visible, and how many sections have to be skipped for the rest to
+/// fit.
+fn scroll_sections(area: Rect, heights: &[u16], scroll: usize) -> (Vec<Option<Rect>>, usize) {
+    let fits_from = (0..heights.len())
+        .find(|&skip| heights[skip..].iter().sum::<u16>() <= area.height)
+        .unwrap_or(heights.len() - 1);
+    let skip = scroll.min(fits_from);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            heights[skip..]
+                .iter()
+                .map(|h| Constraint::Length(*h))
+                .chain([Constraint::Min(0)]),
+        )
+        .split(area);
+
+    let sections = (0..heights.len())
+        .map(|idx| idx.checked_sub(skip).map(|i| layout[i]))
+        .collect();
+    (sections, fits_from)
+}
+
+fn render_scroll_hint(frame: &mut Frame, area: Rect, scroll: usize, fits_from: usize) {
+    if fits_from == 0 {
+        return;
+    }
+
+    let hint = format!(
+        " PgUp/PgDn to scroll ({}/{fits_from}) ",
+        scroll.min(fits_from)
+    );
+    #[allow(clippy::cast_possible_truncation)]
+    let hint_width = hint.len() as u16;
+    frame.render_widget(
+        Paragraph::new(hint).style(Style::default().fg(theme::current().warning)),
+        Rect::new(
+            area.right().saturating_sub(hint_width + 1),
+            area.bottom().saturating_sub(1),
+            hint_width.min(area.width),
+            1,
+        ),
+    );
+}
+
+fn render_default_overview(
+    frame: &mut Frame,
+    ctx: &AppContext,
+    main_layout: Rect,
+    areas: &mut ClickAreas,
+) {
     if ctx.mode == Mode::Arena {
         return;
     }
 
-    let overview_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(2), // champ name
-            Constraint::Length(6), // primary / secondary runes
-            Constraint::Length(6), // shards / ability order
-            Constraint::Length(8), // items
-            Constraint::Length(1), // best matchups
-            Constraint::Length(1), // worst matchups
-            Constraint::Min(0),    // rest
-        ])
-        .split(main_layout);
-    let rune_split = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(overview_layout[1]);
-    let shard_ability_split = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(overview_layout[2]);
+    let (sections, fits_from) = scroll_sections(
+        main_layout,
+        &default_section_heights(main_layout.width),
+        ctx.overview_scroll,
+    );
+    let split_direction = if main_layout.width < STACKED_WIDTH {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    };
+    let halves = |area: Rect| {
+        Layout::default()
+            .direction(split_direction)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area)
+    };
+    let rune_split = sections[1].map(halves);
+    let shard_ability_split = sections[2].map(halves);
+    let item_area = sections[3];
+    let matchup_rows = sections[4].map(|area| {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // best matchups
+                Constraint::Length(1), // worst matchups
+                Constraint::Length(1), // ban / pick suggestions
+            ])
+            .split(area)
+    });
 
-    frame.render_widget(rune_path::make_placeholder(), rune_split[1]);
-    frame.render_widget(rune_path::make_placeholder(), rune_split[0]);
-    frame.render_widget(shards::make_placeholder(), shard_ability_split[0]);
-    frame.render_widget(ability_order::make_placeholder(), shard_ability_split[1]);
-    frame.render_widget(items::make_placeholder(None), overview_layout[3]);
+    if let Some(split) = &rune_split {
+        frame.render_widget(rune_path::make_placeholder(), split[1]);
+        frame.render_widget(rune_path::make_placeholder(), split[0]);
+    }
+    if let Some(split) = &shard_ability_split {
+        frame.render_widget(shards::make_placeholder(), split[0]);
+        frame.render_widget(ability_order::make_placeholder(), split[1]);
+    }
+    if let Some(area) = item_area {
+        frame.render_widget(items::make_placeholder(None), area);
+    }
 
     if let Some(overview) = &ctx.selected_champ_overview {
-        if let Some(selected) = &ctx.selected_champ {
-            frame.render_widget(
-                champ_name::make(ctx, overview, selected),
-                overview_layout[0],
-            );
+        if let Some(selected) = &ctx.selected_champ
+            && let Some(area) = sections[0]
+        {
+            frame.render_widget(champ_name::make(ctx, overview, selected), area);
         }
 
-        let item_columns = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-            ])
-            .split(overview_layout[3]);
-
         if let Overview::Default(d) = overview {
-            frame.render_widget(
-                shards::make(&d.shards.shard_ids),
-                shard_ability_split[0].inner(Margin::new(1, 1)),
-            );
-
-            frame.render_widget(
-                Paragraph::new(spells::make(ctx, &d.summoner_spells.spell_ids)),
-                Rect::new(
-                    shard_ability_split[0].x + 1,
-                    shard_ability_split[0].y + 4,
-                    shard_ability_split[0].width - 1,
-                    1,
-                ),
-            );
-
-            rune_path::make(d, &ctx.api.runes)
-                .into_iter()
-                .zip(rune_split.iter())
-                .for_each(|(w, r)| frame.render_widget(w, *r));
-
-            items::make_default(d, &ctx.api.items)
-                .into_iter()
-                .zip(item_columns.iter())
-                .for_each(|(w, r)| frame.render_widget(w, *r));
+            if let Some(split) = &shard_ability_split {
+                frame.render_widget(
+                    shards::make(&d.shards.shard_ids, &ctx.api.stat_shards),
+                    split[0].inner(Margin::new(1, 1)),
+                );
+
+                frame.render_widget(
+                    Paragraph::new(spells::make(ctx, &d.summoner_spells.spell_ids)),
+                    Rect::new(split[0].x + 1, split[0].y + 4, split[0].width - 1, 1),
+                );
+            }
+
+            if let Some(split) = &rune_split {
+                rune_path::make(d, &ctx.api.runes)
+                    .into_iter()
+                    .zip(split.iter())
+                    .for_each(|(w, r)| frame.render_widget(w, *r));
+                areas.runes.extend(
+                    rune_path::rune_ids(d, &ctx.api.runes)
+                        .into_iter()
+                        .zip(split.iter())
+                        .map(|(ids, area)| IdColumn { area: *area, ids }),
+                );
+            }
+
+            if let Some(area) = item_area {
+                let item_columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(20),
+                    ])
+                    .split(area);
+                items::make_default(d, &ctx.api.items)
+                    .into_iter()
+                    .zip(item_columns.iter())
+                    .for_each(|(w, r)| frame.render_widget(w, *r));
+                areas.items.extend(
+                    items::default_item_ids(d, &ctx.api.items)
+                        .into_iter()
+                        .zip(item_columns.iter())
+                        .map(|(ids, area)| IdColumn { area: *area, ids }),
+                );
+            }
         }
 
-        for (w, r) in ability_order::make(shard_ability_split[1].inner(Margin::new(1, 1)), overview)
-        {
-            frame.render_widget(w, r);
+        if let Some(split) = &shard_ability_split {
+            for (w, r) in ability_order::make(
+                split[1].inner(Margin::new(1, 1)),
+                overview,
+                None,
+                &ctx.spell_names(),
+            ) {
+                frame.render_widget(w, r);
+            }
         }
     }
 
-    if let Some(matchups) = &ctx.selected_champ_matchups {
-        let [best, worst] = matchups::make(matchups, &ctx.champ_by_key);
-        frame.render_widget(best, overview_layout[4]);
-        frame.render_widget(worst, overview_layout[5]);
+    if let Some(rows) = &matchup_rows {
+        if let Some(matchups) = &ctx.selected_champ_matchups {
+            let [best, worst] = matchups::make(matchups, &ctx.champ_by_key);
+            frame.render_widget(best, rows[0]);
+            frame.render_widget(worst, rows[1]);
+        }
+
+        if let Some(session) = &ctx.champ_select {
+            if session.is_ban_phase() {
+                // Only filled while nothing is hovered, then it's based on our most-played
+                // champions instead.
+                let worst_matchups = match &ctx.selected_champ_matchups {
+                    Some(m) if ctx.ban_suggestions.is_empty() => &m.worst_matchups[..],
+                    _ => &ctx.ban_suggestions[..],
+                };
+                if !worst_matchups.is_empty() {
+                    frame.render_widget(
+                        matchups::make_ban_suggestions(
+                            worst_matchups,
+                            &session.unavailable_champion_ids(),
+                            &ctx.champ_by_key,
+                        ),
+                        rows[2],
+                    );
+                }
+            } else if !ctx.counter_picks.is_empty() {
+                frame.render_widget(counter_picks::make(&ctx.counter_picks), rows[2]);
+            }
+        }
     }
+
+    render_scroll_hint(frame, main_layout, ctx.overview_scroll, fits_from);
 }
 
-fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect) {
+fn render_arena_overview(
+    frame: &mut Frame,
+    ctx: &AppContext,
+    main_layout: Rect,
+    areas: &mut ClickAreas,
+) {
     if ctx.mode != Mode::Arena {
         return;
     }
 
-    let overview_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(2),  // champ name
-            Constraint::Length(8),  // augments
-            Constraint::Length(5),  // non-prismatic items
-            Constraint::Length(12), // prismatic items / champ synergies
-            Constraint::Min(0),     // rest
-        ])
-        .split(main_layout);
+    let (sections, fits_from) =
+        scroll_sections(main_layout, &ARENA_SECTION_HEIGHTS, ctx.overview_scroll);
+
+    let augment_columns = sections[1].map(|area| {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+            .split(area)
+    });
+
+    let item_columns = sections[2].map(|area| {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(1, 5),
+                Constraint::Ratio(1, 5),
+            ])
+            .split(area)
+    });
 
     let prismatic_synergies_layout = Layout::default()
         .direction(Direction::Horizontal)
@@ -146,15 +296,19 @@ fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect)
             Constraint::Ratio(1, 4),
             Constraint::Ratio(2, 4),
         ])
-        .split(overview_layout[3]);
+        .split(sections[ARENA_SECTION_HEIGHTS.len() - 1].unwrap_or(main_layout));
 
-    let ability_order_layout = Layout::default()
+    let ability_consumables_layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(6)])
+        .constraints([Constraint::Length(6), Constraint::Length(6)])
         .split(prismatic_synergies_layout[2]);
 
-    frame.render_widget(augments::make_placeholder(), overview_layout[1]);
-    frame.render_widget(items::make_placeholder(None), overview_layout[2]);
+    if let Some(area) = sections[1] {
+        frame.render_widget(augments::make_placeholder(), area);
+    }
+    if let Some(area) = sections[2] {
+        frame.render_widget(items::make_placeholder(None), area);
+    }
     frame.render_widget(
         items::make_placeholder(Some("Prismatic Items")),
         prismatic_synergies_layout[0],
@@ -163,65 +317,212 @@ fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect)
         champ_synergy::make_placeholder(),
         prismatic_synergies_layout[1],
     );
-    frame.render_widget(ability_order::make_placeholder(), ability_order_layout[0]);
+    frame.render_widget(
+        ability_order::make_placeholder(),
+        ability_consumables_layout[0],
+    );
+    frame.render_widget(
+        items::make_placeholder(Some("Consumables")),
+        ability_consumables_layout[1],
+    );
+
+    // Until we lock in, suggest champions that pair well with our partner instead.
+    let partner_candidates = ctx
+        .champ_select
+        .as_ref()
+        .filter(|s| !s.is_locked_in() && !ctx.partner_synergies.is_empty())
+        .and(ctx.arena_partner)
+        .and_then(|id| ctx.champ_by_key.get(&id.to_string()));
+
+    if let Some(partner) = partner_candidates {
+        frame.render_widget(
+            champ_synergy::make_partner_candidates(
+                partner,
+                &ctx.partner_synergies,
+                &ctx.champ_by_key,
+                ctx.available_champs.as_ref(),
+            ),
+            prismatic_synergies_layout[1],
+        );
+    }
 
     if let Some(overview) = &ctx.selected_champ_overview {
-        if let Some(selected) = &ctx.selected_champ {
-            frame.render_widget(
-                champ_name::make(ctx, overview, selected),
-                overview_layout[0],
-            );
+        if let Some(selected) = &ctx.selected_champ
+            && let Some(area) = sections[0]
+        {
+            frame.render_widget(champ_name::make(ctx, overview, selected), area);
         }
-        let augment_columns = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-            ])
-            .split(overview_layout[1]);
-
-        let item_columns = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Ratio(1, 4),
-                Constraint::Ratio(1, 4),
-                Constraint::Ratio(1, 4),
-                Constraint::Ratio(1, 4),
-            ])
-            .split(overview_layout[2]);
 
         if let Overview::Arena(d) = overview {
-            let augments = augments::make(d, &ctx.api.arena_augments);
-
-            augments
-                .into_iter()
-                .zip(augment_columns.iter())
-                .for_each(|(w, r)| frame.render_widget(w, *r));
-
-            let [regular @ .., prismatic] = items::make_arena(d, &ctx.api.items);
-
-            regular
-                .into_iter()
-                .zip(item_columns.iter())
-                .for_each(|(w, r)| frame.render_widget(w, *r));
+            if let Some(columns) = augment_columns {
+                augments::make(d, &ctx.api.arena_augments)
+                    .into_iter()
+                    .zip(columns.iter())
+                    .for_each(|(w, r)| frame.render_widget(w, *r));
+            }
+
+            let [regular @ .., consumables, prismatic] = items::make_arena(d, &ctx.api.items);
+            let [regular_ids @ .., consumable_ids, prismatic_ids] =
+                items::arena_item_ids(d, &ctx.api.items);
+
+            if let Some(columns) = item_columns {
+                regular
+                    .into_iter()
+                    .zip(columns.iter())
+                    .for_each(|(w, r)| frame.render_widget(w, *r));
+                areas.items.extend(
+                    regular_ids
+                        .into_iter()
+                        .zip(columns.iter())
+                        .map(|(ids, area)| IdColumn { area: *area, ids }),
+                );
+            }
 
             frame.render_widget(prismatic, prismatic_synergies_layout[0]);
-            frame.render_widget(
-                champ_synergy::make(d, &ctx.champ_by_key),
-                prismatic_synergies_layout[1],
-            );
+            frame.render_widget(consumables, ability_consumables_layout[1]);
+            areas.items.push(IdColumn {
+                area: prismatic_synergies_layout[0],
+                ids: prismatic_ids,
+            });
+            areas.items.push(IdColumn {
+                area: ability_consumables_layout[1],
+                ids: consumable_ids,
+            });
+            if partner_candidates.is_none() {
+                frame.render_widget(
+                    champ_synergy::make(d, &ctx.champ_by_key, ctx.arena_partner),
+                    prismatic_synergies_layout[1],
+                );
+            }
         }
 
-        for (w, r) in
-            ability_order::make(ability_order_layout[0].inner(Margin::new(1, 1)), overview)
-        {
+        for (w, r) in ability_order::make(
+            ability_consumables_layout[0].inner(Margin::new(1, 1)),
+            overview,
+            None,
+            &ctx.spell_names(),
+        ) {
             frame.render_widget(w, r);
         }
     }
+
+    render_scroll_hint(frame, main_layout, ctx.overview_scroll, fits_from);
 }
 
-pub fn render(frame: &mut Frame, ctx: &AppContext) {
+fn render_in_game(
+    frame: &mut Frame,
+    ctx: &AppContext,
+    live: &AllGameData,
+    main_layout: Rect,
+    areas: &mut ClickAreas,
+) {
+    let in_game_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // status / skill up reminder
+            Constraint::Length(3), // build progress
+            Constraint::Length(3), // next purchase
+            Constraint::Length(6), // ability order
+            Constraint::Min(0),    // build path / current items
+        ])
+        .split(main_layout);
+    let item_split = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(in_game_layout[4]);
+
+    let owned_item_ids = live
+        .me()
+        .map(|me| me.items.iter().map(|i| i.item_id).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let progress = ctx
+        .selected_champ_overview
+        .as_ref()
+        .map(|overview| BuildProgress::new(overview, &owned_item_ids))
+        .unwrap_or_default();
+
+    let abilities = ctx
+        .selected_champ_overview
+        .as_ref()
+        .map(Overview::abilities);
+    frame.render_widget(
+        in_game::make_status(live, abilities.as_ref().map(|a| a.ability_order.as_slice())),
+        in_game_layout[0],
+    );
+    frame.render_widget(in_game::make_progress(&progress), in_game_layout[1]);
+    frame.render_widget(
+        in_game::make_next_purchase(&progress, live, &ctx.api.items),
+        in_game_layout[2],
+    );
+    frame.render_widget(
+        in_game::make_build_path(&progress, &ctx.api.items),
+        item_split[0],
+    );
+    frame.render_widget(in_game::make_current_items(live), item_split[1]);
+    areas.items.push(IdColumn {
+        area: item_split[0],
+        ids: progress.path.iter().map(|(id, _)| *id).collect(),
+    });
+    areas.items.push(IdColumn {
+        area: item_split[1],
+        ids: owned_item_ids,
+    });
+
+    frame.render_widget(ability_order::make_placeholder(), in_game_layout[3]);
+    if let Some(overview) = &ctx.selected_champ_overview {
+        let next_level = usize::try_from(live.active_player.skill_points_spent()).ok();
+        for (w, r) in ability_order::make(
+            in_game_layout[3].inner(Margin::new(1, 1)),
+            overview,
+            next_level,
+            &ctx.spell_names(),
+        ) {
+            frame.render_widget(w, r);
+        }
+    }
+}
+
+fn render_champion_search(frame: &mut Frame, ctx: &AppContext, area: Rect, areas: &mut ClickAreas) {
+    let champion_search_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let (champ_list, mut champ_list_state) = champ_list::make(ctx);
+    frame.render_stateful_widget(champ_list, champion_search_layout[1], &mut champ_list_state);
+    areas.champ_list = Some(ListArea {
+        area: champion_search_layout[1],
+        offset: champ_list_state.offset(),
+    });
+
+    if ctx.champ_list.is_empty() {
+        let text = "No results :(";
+        let length = 13u16;
+        let no_results_text = Paragraph::new(text).style(Style::default().fg(theme::current().bad));
+        let no_results_offset = Rect::new(
+            champion_search_layout[1].x
+                + champion_search_layout[1].width.saturating_sub(length) / 2,
+            champion_search_layout[1].y + 2,
+            length.min(champion_search_layout[1].width),
+            1,
+        );
+        frame.render_widget(no_results_text, no_results_offset);
+    }
+
+    frame.render_widget(
+        search::make(ctx, champion_search_layout[0].width.saturating_sub(2)),
+        champion_search_layout[0],
+    );
+    areas.search = Some(champion_search_layout[0]);
+}
+
+/// Whether the compact layout should show the champion list instead of the overview.
+fn compact_shows_champions(ctx: &AppContext) -> bool {
+    matches!(ctx.state, State::TextInput | State::ChampScroll)
+        || (ctx.selected_champ.is_none() && ctx.show_left_pane)
+}
+
+pub fn render(frame: &mut Frame, ctx: &AppContext, areas: &mut ClickAreas) {
     let frame_size = frame.area();
 
     let app_border = Layout::default()
@@ -231,13 +532,13 @@ pub fn render(frame: &mut Frame, ctx: &AppContext) {
 
     frame.render_widget(app_border::make(ctx), app_border[0]);
 
-    if frame_size.width <= 105 || frame_size.height <= 28 {
+    if frame_size.width < MIN_WIDTH || frame_size.height < MIN_HEIGHT {
         frame.render_widget(
-            Paragraph::new(TOO_SMALL_MESSAGE).style(Style::default().fg(Color::Red).bold()),
-            app_border[0].inner(Margin::new(
-                (frame_size.width - TOO_SMALL_MESSAGE_LENGTH) / 2,
-                frame_size.height / 2 - 1,
-            )),
+            Paragraph::new(TOO_SMALL_MESSAGE)
+                .style(Style::default().fg(theme::current().bad).bold())
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true }),
+            app_border[0].inner(Margin::new(1, frame_size.height.saturating_sub(2) / 2)),
         );
         return;
     }
@@ -253,71 +554,197 @@ pub fn render(frame: &mut Frame, ctx: &AppContext) {
         return;
     }
 
-    let main_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length(if ctx.show_left_pane { 19 } else { 0 }),
-            Constraint::Min(0),
-        ])
-        .margin(1)
-        .split(app_border[0].inner(Margin::new(1, 1)));
-
-    let champion_search_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(0)])
-        .split(main_layout[0]);
+    let compact = frame_size.width <= COMPACT_WIDTH || frame_size.height <= COMPACT_HEIGHT;
+    let (main_area, show_overview) = if compact {
+        // Tabs on the first line, and either the champion list or the overview below.
+        let compact_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(app_border[0].inner(Margin::new(1, 1)));
+        let show_champions = compact_shows_champions(ctx);
+        frame.render_widget(
+            Tabs::new(["Champions", "Build"])
+                .select(usize::from(!show_champions))
+                .style(Style::default().fg(theme::current().muted))
+                .highlight_style(Style::default().fg(theme::current().selected).bold()),
+            compact_layout[0],
+        );
+        if show_champions {
+            render_champion_search(frame, ctx, compact_layout[1], areas);
+        }
+        (compact_layout[1], !show_champions)
+    } else {
+        let main_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(if ctx.show_left_pane { 19 } else { 0 }),
+                Constraint::Min(0),
+            ])
+            .margin(1)
+            .split(app_border[0].inner(Margin::new(1, 1)));
+        render_champion_search(frame, ctx, main_layout[0], areas);
+        (main_layout[1], true)
+    };
 
-    let (champ_list, mut champ_list_state) = champ_list::make(ctx);
-    frame.render_stateful_widget(champ_list, champion_search_layout[1], &mut champ_list_state);
+    match (&ctx.live_game, ctx.mode) {
+        _ if !show_overview => {}
+        _ if ctx.state == State::AugmentExplorer => {
+            augment_explorer::render(frame, ctx, main_area);
+        }
+        _ if ctx.state == State::ChampionDetails => {
+            champion_details::render(frame, ctx, main_area);
+        }
+        _ if ctx.comparison.is_some() => compare::render(frame, ctx, main_area),
+        (Some(live), _) if ctx.show_in_game_view => {
+            render_in_game(frame, ctx, live, main_area, areas);
+        }
+        (_, Mode::Arena) => render_arena_overview(frame, ctx, main_area, areas),
+        _ => render_default_overview(frame, ctx, main_area, areas),
+    }
 
-    if ctx.champ_list.is_empty() {
-        let text = "No results :(";
-        let length = 13u16;
-        let no_results_text = Paragraph::new(text).style(Style::default().fg(Color::Red));
-        let no_results_offset = Rect::new(
-            champion_search_layout[1].x + (champion_search_layout[1].width - length) / 2,
-            champion_search_layout[1].y + 2,
-            length,
-            1,
+    let selection_columns = |target| match target {
+        SelectionTarget::Items => &areas.items,
+        SelectionTarget::Runes => &areas.runes,
+    };
+    let selected_area = ctx.selection.and_then(|selection| {
+        selection_columns(selection.target)
+            .get(selection.column)
+            .and_then(|c| c.row_area(selection.row))
+    });
+    if let Some(area) = selected_area {
+        frame.buffer_mut().set_style(
+            area,
+            Style::default()
+                .fg(theme::current().selected)
+                .add_modifier(Modifier::REVERSED),
         );
-        frame.render_widget(no_results_text, no_results_offset);
     }
 
-    frame.render_widget(search::make(ctx), champion_search_layout[0]);
-
-    match ctx.mode {
-        Mode::Arena => render_arena_overview(frame, ctx, main_layout[1]),
-        _ => render_default_overview(frame, ctx, main_layout[1]),
+    if ctx.state == State::RuneSelect
+        && let Some(preview) = selection::make_rune_preview(ctx)
+    {
+        let height = 6.min(main_area.height);
+        let mut preview_area = Rect::new(
+            main_area.x,
+            main_area.bottom() - height,
+            main_area.width,
+            height,
+        );
+        // Move to the top when the bottom would cover the selected rune.
+        if selected_area.is_some_and(|area| area.intersects(preview_area)) {
+            preview_area.y = main_area.y;
+        }
+        frame.render_widget(Clear, preview_area);
+        frame.render_widget(preview, preview_area);
     }
 
     if ctx.state == State::ModeSelect {
-        show_list_popup!(frame, mode_select::make(ctx), main_layout[1]);
+        show_list_popup!(frame, mode_select::make(ctx), main_area, areas);
     }
 
     if ctx.state == State::VersionSelect {
-        show_list_popup!(frame, version_select::make(ctx), main_layout[1]);
+        show_list_popup!(frame, version_select::make(ctx), main_area, areas);
     }
 
     if ctx.state == State::RegionSelect {
-        show_list_popup!(frame, region_select::make(ctx), main_layout[1]);
+        show_list_popup!(frame, region_select::make(ctx), main_area, areas);
     }
 
     if ctx.state == State::RoleSelect {
-        show_list_popup!(frame, role_select::make(ctx), main_layout[1]);
+        show_list_popup!(frame, role_select::make(ctx), main_area, areas);
     }
 
     if ctx.state == State::BuildSelect {
-        show_list_popup!(frame, build_select::make(ctx), main_layout[1]);
+        show_list_popup!(frame, build_select::make(ctx), main_area, areas);
+    }
+
+    if ctx.state == State::ChatPreview
+        && let Some(message) = &ctx.chat_preview
+    {
+        let (preview, minimum_area) = build_summary::make_preview(message);
+        let safe_area = main_area.inner(Margin::new(
+            (main_area.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            (main_area.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
+        ));
+        frame.render_widget(Block::new().bg(theme::current().background), main_area);
+        frame.render_widget(Clear, safe_area);
+        frame.render_widget(preview, safe_area.inner(Margin::new(1, 1)));
+        areas.popup = Some(safe_area);
+    }
+
+    if ctx.state == State::Details
+        && let Some(selected) = &ctx.details
+    {
+        let (details, minimum_area) = details::make(selected);
+        let safe_area = main_area.inner(Margin::new(
+            (main_area.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            (main_area.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
+        ));
+        frame.render_widget(Block::new().bg(theme::current().background), main_area);
+        frame.render_widget(Clear, safe_area);
+        frame.render_widget(details, safe_area.inner(Margin::new(1, 1)));
+        areas.popup = Some(safe_area);
     }
 
     if ctx.state == State::HelpMenu {
-        let (help_menu, minimum_area) = crate::components::help_menu::make();
-        let safe_area = main_layout[1].inner(Margin::new(
-            (main_layout[1].width - minimum_area.width) / 2 - 1,
-            (main_layout[1].height - minimum_area.height) / 2 - 1,
+        let (help_menu, minimum_area) = crate::components::help_menu::make(&ctx.keymap);
+        let safe_area = main_area.inner(Margin::new(
+            (main_area.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            (main_area.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
         ));
-        frame.render_widget(Block::new().bg(Color::Black), main_layout[1]);
+        frame.render_widget(Block::new().bg(theme::current().background), main_area);
         frame.render_widget(Clear, safe_area);
         frame.render_widget(help_menu, safe_area.inner(Margin::new(1, 1)));
+        areas.popup = Some(safe_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEIGHTS: [u16; 3] = [4, 3, 5];
+
+    #[test]
+    fn scroll_sections_fit() {
+        let area = Rect::new(0, 0, 20, 15);
+        let (sections, fits_from) = scroll_sections(area, &HEIGHTS, 2);
+        assert_eq!(fits_from, 0);
+        assert_eq!(
+            sections,
+            [
+                Some(Rect::new(0, 0, 20, 4)),
+                Some(Rect::new(0, 4, 20, 3)),
+                Some(Rect::new(0, 7, 20, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn scroll_sections_skip_from_the_top() {
+        let area = Rect::new(0, 0, 20, 9);
+        let (sections, fits_from) = scroll_sections(area, &HEIGHTS, 0);
+        assert_eq!(fits_from, 1);
+        // Nothing is skipped until the user scrolls, the last section just gets cut off.
+        assert!(sections.iter().all(Option::is_some));
+
+        let (sections, _) = scroll_sections(area, &HEIGHTS, 1);
+        assert_eq!(
+            sections,
+            [
+                None,
+                Some(Rect::new(0, 0, 20, 3)),
+                Some(Rect::new(0, 3, 20, 5))
+            ]
+        );
+    }
+
+    #[test]
+    fn scroll_sections_stop_at_the_last_section() {
+        let area = Rect::new(0, 0, 20, 2);
+        let (sections, fits_from) = scroll_sections(area, &HEIGHTS, 10);
+        assert_eq!(fits_from, 2);
+        assert_eq!(sections[..2], [None, None]);
+        assert_eq!(sections[2], Some(Rect::new(0, 0, 20, 2)));
     }
 }